@@ -3,20 +3,16 @@ PRAGMA journal_mode = WAL;
 
 CREATE TABLE IF NOT EXISTS Groups (
     id INTEGER PRIMARY KEY,
-    name TEXT NOT NULL -- not unqiue
-
-    -- TODO: need to be fetched separately
-    -- direction_id INTEGER,
-    -- speciality_id INTEGER
+    name TEXT NOT NULL, -- not unqiue
+    direction_id INTEGER,
+    speciality_id INTEGER
 ) WITHOUT ROWID;
 
 CREATE TABLE IF NOT EXISTS Teachers (
     id INTEGER PRIMARY KEY,
-    name TEXT NOT NULL
-    -- short_name TEXT NOT NULL -- Compute this instead
-
-    -- TODO: need to be fetched separately
-    -- department_id INTEGER NOT NULL
+    name TEXT NOT NULL,
+    abbr TEXT NOT NULL, -- may be the only name known upstream
+    department_id INTEGER
 ) WITHOUT ROWID;
 
 CREATE TABLE IF NOT EXISTS Subjects (
@@ -27,12 +23,12 @@ CREATE TABLE IF NOT EXISTS Subjects (
 
 CREATE TABLE IF NOT EXISTS Auditoriums (
     id INTEGER PRIMARY KEY,
-    name TEXT UNIQUE NOT NULL
-
-    -- TODO: need to be fetched separately, probably may be derived from name
-    -- floor INTEGER NOT NULL,
-    -- building TEXT NOT NULL,
-    -- has_power INTEGER NOT NULL -- CHECK (has_power IN (0, 1))
+    name TEXT UNIQUE NOT NULL,
+    -- derived from the name when missing upstream, see `AuditoriumName`
+    floor INTEGER NOT NULL,
+    building TEXT NOT NULL,
+    has_power BOOLEAN NOT NULL CHECK (has_power IN (0, 1)),
+    online BOOLEAN NOT NULL DEFAULT 0 CHECK (online IN (0, 1)) -- distance learning
 ) WITHOUT ROWID;
 
 
@@ -57,10 +53,10 @@ CREATE TABLE IF NOT EXISTS Events (
     auditorium_id INTEGER NOT NULL REFERENCES Auditoriums(id) ON DELETE CASCADE,
     -- kind TEXT CHECK (kind IN ('Лк', 'Пз', 'Лб', 'Конс', 'Зал', 'Екз', 'КП/КР')) NOT NULL,
     kind INTEGER NOT NULL, -- CHECK (kind >= 0 AND kind <= 6), -- REFERENCES EventKind(id) ON DELETE CASCADE,
+    kind_label TEXT, -- raw upstream label of an unknown kind (255)
     count INTEGER NOT NULL, -- sequence number of the particular class
     starts_at INTEGER NOT NULL,
-    duration INTEGER NOT NULL DEFAULT 95 -- minutes
-    -- ends_at INTEGER NOT NULL -- = starts_at + 95 mins
+    ends_at INTEGER NOT NULL
 ) WITHOUT ROWID;
 
 CREATE TABLE IF NOT EXISTS EventGroups (
@@ -1,30 +1,198 @@
 use crate::Database;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Auditorium {
-    pub id: i64,
-    pub name: String,
-    // pub floor: i64,
-    // pub has_power: i64,
-    // pub building: String,
-}
+use schedule_model::{
+    Auditorium,
+    analysis::{AuditoriumQuery, FreeAuditorium},
+};
+use sqlx::SqliteConnection;
+
+impl Database {
+    pub async fn auditorium(&self, id: i64) -> sqlx::Result<Option<Auditorium>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, floor, building, has_power FROM Auditoriums WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await?
+        .map(|a| Auditorium {
+            id: a.id,
+            name: a.name,
+            floor: a.floor.try_into().unwrap_or_default(),
+            power: a.has_power,
+            building: a.building,
+        }))
+    }
 
-impl Auditorium {
-    pub async fn fetch(db: &Database, id: i64) -> sqlx::Result<Option<Self>> {
-        sqlx::query_as!(Self, "SELECT * FROM Auditoriums WHERE id = ?", id)
-            .fetch_optional(&db.0)
-            .await
+    pub async fn auditoriums(&self) -> sqlx::Result<Vec<Auditorium>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, floor, building, has_power FROM Auditoriums ORDER BY name"
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(|a| Auditorium {
+            id: a.id,
+            name: a.name,
+            floor: a.floor.try_into().unwrap_or_default(),
+            power: a.has_power,
+            building: a.building,
+        })
+        .collect())
     }
 
-    pub(crate) async fn insert(&self, db: &Database) -> sqlx::Result<()> {
-        sqlx::query_as!(
-            Self,
-            "INSERT OR REPLACE INTO Auditoriums VALUES (?, ?)",
-            self.id,
-            self.name
+    /// Stored auditoriums matching the `query` without events between `from` and `to`,
+    /// the ones free for longer go first. The online ones only if the `query` includes them.
+    pub async fn free_auditoriums(
+        &self,
+        query: &AuditoriumQuery,
+        from: i64,
+        to: i64,
+    ) -> sqlx::Result<Vec<FreeAuditorium>> {
+        // Stored lowercase, see `Auditorium::with_name`
+        let building = query.building.as_deref().map(str::to_lowercase);
+        let mut free: Vec<_> = sqlx::query!(
+            r#"SELECT a.id, a.name, a.floor, a.building, a.has_power,
+                (SELECT MIN(e.starts_at) FROM Events e
+                    WHERE e.auditorium_id = a.id AND e.starts_at >= ?) AS "free_until: i64"
+            FROM Auditoriums a
+            WHERE NOT EXISTS (
+                SELECT 1 FROM Events e
+                WHERE e.auditorium_id = a.id AND e.starts_at < ? AND e.ends_at > ?
+            )
+              AND (?4 IS NULL OR a.building = ?4)
+              AND (?5 IS NULL OR a.floor = ?5)
+              AND (?6 IS NULL OR a.has_power = ?6)
+              AND (?7 OR NOT a.online)"#,
+            to,
+            to,
+            from,
+            building,
+            query.floor,
+            query.power,
+            query.include_online,
         )
-        .execute(&db.0)
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(|a| FreeAuditorium {
+            auditorium: Auditorium {
+                id: a.id,
+                name: a.name,
+                floor: a.floor.try_into().unwrap_or_default(),
+                power: a.has_power,
+                building: a.building,
+            },
+            free_until: a.free_until,
+        })
+        .collect();
+        free.sort();
+        Ok(free)
+    }
+
+    pub async fn insert_auditoriums<'a>(
+        &self,
+        auditoriums: impl IntoIterator<Item = &'a Auditorium>,
+    ) -> sqlx::Result<()> {
+        let mut tx = self.0.begin().await?;
+        for auditorium in auditoriums {
+            insert(&mut tx, auditorium).await?;
+        }
+        tx.commit().await
+    }
+}
+
+async fn insert(conn: &mut SqliteConnection, auditorium: &Auditorium) -> sqlx::Result<()> {
+    let online = auditorium.is_online();
+    sqlx::query!(
+        "INSERT OR REPLACE INTO Auditoriums(id, name, floor, building, has_power, online)
+        VALUES (?, ?, ?, ?, ?, ?)",
+        auditorium.id,
+        auditorium.name,
+        auditorium.floor,
+        auditorium.building,
+        auditorium.power,
+        online,
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use schedule_model::{Event, Timetable};
+
+    const HOUR: i64 = 60 * 60;
+
+    fn auditorium(id: i64, floor: i8, power: bool) -> Auditorium {
+        Auditorium {
+            id,
+            name: id.to_string(),
+            floor,
+            power,
+            building: "г".into(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn free_auditoriums() -> sqlx::Result<()> {
+        let db = Database::in_memory().await?;
+
+        db.insert_auditoriums(&[
+            auditorium(1, 1, true),
+            auditorium(2, 1, false),
+            auditorium(3, 2, true),
+            Auditorium::online(-4).unwrap(),
+        ])
         .await?;
+        let subject = schedule_model::Subject {
+            id: 1,
+            abbr: "ВМ".into(),
+            name: "Вища математика".into(),
+        };
+        db.insert_timetable(&Timetable::new(
+            [
+                Event::test(1, 0).lasting(HOUR),
+                Event::test(2, 2 * HOUR).lasting(HOUR),
+                Event::test(3, HOUR / 2).lasting(HOUR).auditorium(2),
+            ],
+            [subject],
+        ))
+        .await?;
+
+        let free = db
+            .free_auditoriums(&AuditoriumQuery::default(), HOUR, 2 * HOUR)
+            .await?;
+        assert_eq!(
+            free.iter()
+                .map(|f| (f.auditorium.id, f.free_until))
+                .collect::<Vec<_>>(),
+            [(3, None), (1, Some(2 * HOUR))]
+        );
+
+        let query = AuditoriumQuery {
+            floor: Some(2),
+            power: Some(true),
+            building: Some("Г".into()),
+            ..Default::default()
+        };
+        assert_eq!(db.free_auditoriums(&query, 0, HOUR).await?.len(), 1);
+
+        let online = AuditoriumQuery {
+            include_online: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            db.free_auditoriums(&online, 0, HOUR).await?[0]
+                .auditorium
+                .id,
+            -4
+        );
+
+        db.close().await;
+
         Ok(())
     }
 }
@@ -1,89 +1,175 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use crate::{Database, Filter};
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub enum EventKind {
-    Lecture = 0,
-    PracticalWork = 1,
-    LaboratoryWork = 2,
-    Consultation = 3,
-    /// Залік
-    FinalTest = 4,
-    Exam = 5,
-    CourseWork = 6,
-    Unknown = 255,
-}
+use schedule_model::{Event, EventKind, Subject, Timetable, TimetableKind};
+use sqlx::SqliteConnection;
 
-impl std::fmt::Display for EventKind {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", *self as u8) // TODO: sketchy
+/// Value of the `kind` column, the raw label of [`EventKind::Unknown`] goes to `kind_label`.
+pub(crate) fn kind_code(kind: &EventKind) -> u8 {
+    match kind {
+        EventKind::Lecture => 0,
+        EventKind::PracticalWork => 1,
+        EventKind::LaboratoryWork => 2,
+        EventKind::Consultation => 3,
+        EventKind::FinalTest => 4, // Залік
+        EventKind::Exam => 5,
+        EventKind::CourseWork => 6,
+        EventKind::Unknown(_) => 255,
     }
 }
-impl From<i64> for EventKind {
-    fn from(value: i64) -> Self {
-        match value {
-            0 => Self::Lecture,
-            1 => Self::PracticalWork,
-            2 => Self::LaboratoryWork,
-            3 => Self::Consultation,
-            4 => Self::FinalTest, // Залік
-            5 => Self::Exam,
-            6 => Self::CourseWork,
-            _ => Self::Unknown,
-        }
+
+pub(crate) fn kind_from_columns(kind: i64, label: Option<String>) -> EventKind {
+    match kind {
+        0 => EventKind::Lecture,
+        1 => EventKind::PracticalWork,
+        2 => EventKind::LaboratoryWork,
+        3 => EventKind::Consultation,
+        4 => EventKind::FinalTest,
+        5 => EventKind::Exam,
+        6 => EventKind::CourseWork,
+        _ => EventKind::Unknown(label.unwrap_or_default()),
     }
 }
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Event {
-    pub id: i64,
-    pub kind: EventKind,
-    pub count: u8,
-    pub subject_id: i64,
-    pub auditorium_id: i64,
-    pub starts_at: i64,
-    pub duration: u8,
-    // pub ends_at: i64,
-}
+impl Database {
+    pub async fn event(&self, id: i64) -> sqlx::Result<Option<Event>> {
+        let Some(e) = sqlx::query!(
+            "SELECT kind, kind_label, count, subject_id, auditorium_id, starts_at, ends_at
+            FROM Events WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let groups = sqlx::query_scalar!("SELECT group_id FROM EventGroups WHERE event_id = ?", id)
+            .fetch_all(&self.0)
+            .await?;
+        let teachers = sqlx::query_scalar!(
+            "SELECT teacher_id FROM EventTeachers WHERE event_id = ?",
+            id
+        )
+        .fetch_all(&self.0)
+        .await?;
 
-impl Event {
-    pub async fn fetch(db: &Database, id: i64) -> sqlx::Result<Option<Self>> {
-        Ok(sqlx::query!("SELECT * FROM Events WHERE id = ?", id)
-            .fetch_optional(&db.0)
-            .await?
-            .map(|e| Self {
-                id,
-                kind: e.kind.into(),
-                count: e.count.try_into().unwrap_or(u8::MAX),
-                subject_id: e.subject_id,
-                auditorium_id: e.auditorium_id,
-                starts_at: e.starts_at,
-                duration: e.duration.try_into().unwrap_or(95),
-            }))
+        Ok(Some(Event {
+            id,
+            starts_at: e.starts_at,
+            ends_at: e.ends_at,
+            kind: kind_from_columns(e.kind, e.kind_label),
+            count: e.count.try_into().unwrap_or(u8::MAX),
+            subject: e.subject_id,
+            auditorium: e.auditorium_id,
+            groups: groups.into_iter().collect(),
+            teachers: teachers.into_iter().collect(),
+        }))
     }
 
-    pub(crate) async fn insert(&self, db: &Database) -> sqlx::Result<()> {
-        let kind = self.kind as u8;
-        sqlx::query_as!(
-            Self,
-            "INSERT OR REPLACE INTO Events(id, kind, count, subject_id, auditorium_id, starts_at, duration) VALUES (?, ?, ?, ?, ?, ?,?)",
-           self.id,
-           kind,
-           self.count,
-           self.subject_id,
-           self.auditorium_id,
-           self.starts_at,
-           self.duration,
+    /// Store the events with their subjects. The groups, teachers and auditoriums must be stored beforehand.
+    pub async fn insert_timetable(&self, timetable: &Timetable) -> sqlx::Result<()> {
+        let mut tx = self.0.begin().await?;
+        for subject in timetable.subjects() {
+            super::subject::insert(&mut tx, subject).await?;
+        }
+        for event in timetable.events() {
+            insert(&mut tx, event).await?;
+        }
+        tx.commit().await
+    }
+
+    /// Events matching the filters (see [`Database::event_ids_filtered`]) with their subjects.
+    pub async fn timetable(
+        &self,
+        include: BTreeSet<Filter>,
+        exclude: BTreeSet<Filter>,
+    ) -> sqlx::Result<Timetable> {
+        let ids = self.event_ids_filtered(include, exclude).await?;
+        if ids.is_empty() {
+            return Ok(Timetable::default());
+        }
+        // As a JSON array for `json_each`, there may be more of them than the bound parameters
+        let ids = format!(
+            "[{}]",
+            ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",")
+        );
+
+        let mut groups: HashMap<i64, HashSet<i64>> = HashMap::new();
+        for g in sqlx::query!(
+            "SELECT event_id, group_id FROM EventGroups
+            WHERE event_id IN (SELECT value FROM json_each(?))",
+            ids
         )
-        .execute(&db.0)
+        .fetch_all(&self.0)
+        .await?
+        {
+            groups.entry(g.event_id).or_default().insert(g.group_id);
+        }
+        let mut teachers: HashMap<i64, HashSet<i64>> = HashMap::new();
+        for t in sqlx::query!(
+            "SELECT event_id, teacher_id FROM EventTeachers
+            WHERE event_id IN (SELECT value FROM json_each(?))",
+            ids
+        )
+        .fetch_all(&self.0)
+        .await?
+        {
+            teachers.entry(t.event_id).or_default().insert(t.teacher_id);
+        }
+
+        let events = sqlx::query!(
+            "SELECT id, kind, kind_label, count, subject_id, auditorium_id, starts_at, ends_at
+            FROM Events WHERE id IN (SELECT value FROM json_each(?))",
+            ids
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(|e| Event {
+            id: e.id,
+            starts_at: e.starts_at,
+            ends_at: e.ends_at,
+            kind: kind_from_columns(e.kind, e.kind_label),
+            count: e.count.try_into().unwrap_or(u8::MAX),
+            subject: e.subject_id,
+            auditorium: e.auditorium_id,
+            groups: groups.remove(&e.id).unwrap_or_default(),
+            teachers: teachers.remove(&e.id).unwrap_or_default(),
+        });
+        let subjects = sqlx::query_as!(
+            Subject,
+            "SELECT id, abbr, name FROM Subjects WHERE id IN (
+                SELECT subject_id FROM Events WHERE id IN (SELECT value FROM json_each(?))
+            )",
+            ids
+        )
+        .fetch_all(&self.0)
         .await?;
-        Ok(())
+
+        Ok(Timetable::new(events, subjects))
+    }
+
+    /// Events of any of the groups, teachers or auditoriums, e.g. to look for their free time.
+    pub async fn timetable_of(
+        &self,
+        kinds: impl IntoIterator<Item = TimetableKind>,
+    ) -> sqlx::Result<Timetable> {
+        let include = kinds
+            .into_iter()
+            .map(|kind| match kind {
+                TimetableKind::Group(id) => Filter::new().groups([id]),
+                TimetableKind::Teacher(id) => Filter::new().teachers([id]),
+                TimetableKind::Auditorium(id) => Filter::new().auditoriums([id]),
+            })
+            .collect();
+        self.timetable(include, BTreeSet::new()).await
     }
 
-    // TODO that should either return Vec<Event>, or be moved out to Database impl or elsewhere
-    pub async fn fetch_filtered(
-        db: &Database,
+    /// Ids of the events matching any of the `include` filters and none of the `exclude` ones.
+    pub async fn event_ids_filtered(
+        &self,
         mut include: BTreeSet<Filter>,
         mut exclude: BTreeSet<Filter>,
     ) -> sqlx::Result<Vec<i64>> {
@@ -114,16 +200,67 @@ impl Event {
         #[cfg(test)] // TODO: consider normal logging
         println!("{query}");
 
-        sqlx::query_scalar(&query).fetch_all(&db.0).await
+        sqlx::query_scalar(&query).fetch_all(&self.0).await
     }
 }
 
+async fn insert(conn: &mut SqliteConnection, event: &Event) -> sqlx::Result<()> {
+    let kind = kind_code(&event.kind);
+    let kind_label = match &event.kind {
+        EventKind::Unknown(label) => Some(label),
+        _ => None,
+    };
+    sqlx::query!(
+        "INSERT OR REPLACE INTO Events(id, kind, kind_label, count, subject_id, auditorium_id, starts_at, ends_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        event.id,
+        kind,
+        kind_label,
+        event.count,
+        event.subject,
+        event.auditorium,
+        event.starts_at,
+        event.ends_at,
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query!("DELETE FROM EventGroups WHERE event_id = ?", event.id)
+        .execute(&mut *conn)
+        .await?;
+    for group in &event.groups {
+        sqlx::query!(
+            "INSERT INTO EventGroups(event_id, group_id) VALUES (?, ?)",
+            event.id,
+            group
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM EventTeachers WHERE event_id = ?", event.id)
+        .execute(&mut *conn)
+        .await?;
+    for teacher in &event.teachers {
+        sqlx::query!(
+            "INSERT INTO EventTeachers(event_id, teacher_id) VALUES (?, ?)",
+            event.id,
+            teacher
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use schedule_model::{Auditorium, Group, Subject, Teacher};
+
     #[sqlx::test]
-    async fn fetch_filtered() -> sqlx::Result<()> {
+    async fn event_ids_filtered() -> sqlx::Result<()> {
         let db = Database::in_memory().await?;
 
         let include = BTreeSet::from([
@@ -144,6 +281,7 @@ mod tests {
                 .auditoriums([1]),
             Filter::new().groups([1]).auditoriums([1]),
             Filter::new().groups([42]),
+            Filter::new().groups([1]).online(true),
         ]);
 
         let exclude = BTreeSet::from([
@@ -152,7 +290,87 @@ mod tests {
             Filter::default(),
         ]);
 
-        Event::fetch_filtered(&db, include, exclude).await?;
+        db.event_ids_filtered(include, exclude).await?;
+
+        db.close().await;
+
+        Ok(())
+    }
+
+    #[sqlx::test]
+    async fn event_round_trip() -> sqlx::Result<()> {
+        let db = Database::in_memory().await?;
+
+        db.insert_groups(&[Group {
+            id: 1,
+            name: "ПЗПІ-23-5".into(),
+            direction_id: None,
+            speciality_id: Some(7),
+        }])
+        .await?;
+        db.insert_teachers(&[Teacher {
+            id: 1,
+            abbr: "Шевченко Т. Г.".into(),
+            name: "Шевченко Тарас Григорович".into(),
+            department_id: None,
+        }])
+        .await?;
+        db.insert_auditoriums(&[Auditorium {
+            id: 1,
+            name: "287".into(),
+            floor: 2,
+            power: true,
+            building: "г".into(),
+        }])
+        .await?;
+
+        let event = Event::test(1, 1757911500)
+            .kind(EventKind::Unknown("Сем".into()))
+            .groups([1])
+            .teachers([1]);
+        let subject = Subject {
+            id: 1,
+            abbr: "ВМ".into(),
+            name: "Вища математика".into(),
+        };
+        let other = Event::test(2, 1757911500).kind(EventKind::Unknown("Фак'".into()));
+        db.insert_timetable(&Timetable::new([event.clone(), other], [subject]))
+            .await?;
+
+        let fetched = db.event(1).await?.unwrap();
+        assert_eq!(fetched.kind, event.kind);
+        assert_eq!(fetched.ends_at, event.ends_at);
+        assert_eq!(fetched.groups, event.groups);
+        assert_eq!(fetched.teachers, event.teachers);
+
+        let timetable = db
+            .timetable(BTreeSet::from([Filter::new().groups([1])]), BTreeSet::new())
+            .await?;
+        assert_eq!(timetable.len(), 1);
+        assert_eq!(timetable.subject(1).map(|s| s.abbr.as_str()), Some("ВМ"));
+
+        let online = |online| {
+            db.timetable(
+                BTreeSet::from([Filter::new().online(online)]),
+                BTreeSet::new(),
+            )
+        };
+        assert_eq!(online(true).await?.len(), 0);
+        assert_eq!(online(false).await?.len(), 2);
+
+        let kinds = |kinds: Vec<EventKind>| {
+            db.event_ids_filtered(
+                BTreeSet::from([Filter::new().kinds(kinds)]),
+                BTreeSet::new(),
+            )
+        };
+        assert_eq!(kinds(vec![EventKind::Unknown("Сем".into())]).await?, [1]);
+        assert_eq!(kinds(vec![EventKind::Unknown("Фак'".into())]).await?, [2]);
+        assert_eq!(
+            kinds(vec![EventKind::Lecture, EventKind::Unknown("Фак'".into())]).await?,
+            [2]
+        );
+        assert!(kinds(vec![EventKind::Lecture]).await?.is_empty());
 
         db.close().await;
 
@@ -1,39 +1,113 @@
 use crate::Database;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Group {
-    pub id: i64,
-    pub name: String,
-}
+use schedule_model::{
+    Group, Timetable, TimetableKind,
+    analysis::{SubgroupChoice, Subgroups},
+};
+use sqlx::SqliteConnection;
+use std::collections::HashSet;
 
-impl Group {
-    pub async fn fetch(db: &Database, id: i64) -> sqlx::Result<Option<Self>> {
-        sqlx::query_as!(Self, "SELECT * FROM Groups WHERE id = ?", id)
-            .fetch_optional(&db.0)
-            .await
+impl Database {
+    pub async fn group(&self, id: i64) -> sqlx::Result<Option<Group>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, direction_id, speciality_id FROM Groups WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await?
+        .map(|g| Group {
+            id: g.id,
+            name: g.name,
+            direction_id: g.direction_id.and_then(|id| id.try_into().ok()),
+            speciality_id: g.speciality_id.and_then(|id| id.try_into().ok()),
+        }))
     }
 
-    pub async fn fetch_by_event(db: &Database, id: i64) -> sqlx::Result<Vec<Self>> {
-        sqlx::query_as!(
-            Self,
-            "SELECT id, name FROM Groups
+    pub async fn groups(&self) -> sqlx::Result<Vec<Group>> {
+        Ok(
+            sqlx::query!("SELECT id, name, direction_id, speciality_id FROM Groups ORDER BY name")
+                .fetch_all(&self.0)
+                .await?
+                .into_iter()
+                .map(|g| Group {
+                    id: g.id,
+                    name: g.name,
+                    direction_id: g.direction_id.and_then(|id| id.try_into().ok()),
+                    speciality_id: g.speciality_id.and_then(|id| id.try_into().ok()),
+                })
+                .collect(),
+        )
+    }
+
+    /// Subgroups of the group by the stored events.
+    pub async fn subgroups(&self, id: i64) -> sqlx::Result<Subgroups> {
+        let timetable = self.timetable_of([TimetableKind::Group(id)]).await?;
+        Ok(Subgroups::detect(&timetable, id))
+    }
+
+    /// Stored events of the group without the classes of the other subgroups than the chosen ones.
+    pub async fn subgroup_timetable(
+        &self,
+        id: i64,
+        choice: &SubgroupChoice,
+    ) -> sqlx::Result<Timetable> {
+        let mut timetable = self.timetable_of([TimetableKind::Group(id)]).await?;
+        let subgroups = Subgroups::detect(&timetable, id);
+        let kept: HashSet<i64> = subgroups
+            .filter(timetable.iter(), choice)
+            .map(|e| e.id)
+            .collect();
+        let dropped: Vec<i64> = timetable
+            .iter()
+            .map(|e| e.id)
+            .filter(|id| !kept.contains(id))
+            .collect();
+        for id in dropped {
+            timetable.remove_event(id);
+        }
+        Ok(timetable)
+    }
+
+    pub async fn groups_by_event(&self, id: i64) -> sqlx::Result<Vec<Group>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, direction_id, speciality_id FROM Groups
             JOIN EventGroups ON group_id = id
             WHERE event_id = ?",
             id
         )
-        .fetch_all(&db.0)
-        .await
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(|g| Group {
+            id: g.id,
+            name: g.name,
+            direction_id: g.direction_id.and_then(|id| id.try_into().ok()),
+            speciality_id: g.speciality_id.and_then(|id| id.try_into().ok()),
+        })
+        .collect())
     }
 
-    pub(crate) async fn insert(&self, db: &Database) -> sqlx::Result<()> {
-        sqlx::query_as!(
-            Self,
-            "INSERT OR REPLACE INTO Groups VALUES (?, ?)",
-            self.id,
-            self.name
-        )
-        .execute(&db.0)
-        .await?;
-        Ok(())
+    pub async fn insert_groups<'a>(
+        &self,
+        groups: impl IntoIterator<Item = &'a Group>,
+    ) -> sqlx::Result<()> {
+        let mut tx = self.0.begin().await?;
+        for group in groups {
+            insert(&mut tx, group).await?;
+        }
+        tx.commit().await
     }
 }
+
+async fn insert(conn: &mut SqliteConnection, group: &Group) -> sqlx::Result<()> {
+    sqlx::query!(
+        "INSERT OR REPLACE INTO Groups(id, name, direction_id, speciality_id) VALUES (?, ?, ?, ?)",
+        group.id,
+        group.name,
+        group.direction_id,
+        group.speciality_id,
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
@@ -4,8 +4,4 @@ mod group;
 mod subject;
 mod teacher;
 
-pub use auditorium::*;
-pub use event::*;
-pub use group::*;
-pub use subject::*;
-pub use teacher::*;
+pub(crate) use event::{kind_code, kind_from_columns};
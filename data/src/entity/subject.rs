@@ -1,29 +1,180 @@
+use super::kind_from_columns;
 use crate::Database;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Subject {
-    pub id: i64,
-    pub abbr: String,
-    pub name: String,
-}
+use schedule_model::{
+    Subject, TimetableKind,
+    analysis::{ACADEMIC_HOUR, Progress, SubjectProgress},
+};
+use sqlx::SqliteConnection;
 
-impl Subject {
-    pub async fn fetch(db: &Database, id: i64) -> sqlx::Result<Option<Self>> {
-        sqlx::query_as!(Self, "SELECT * FROM Subjects WHERE id = ?", id)
-            .fetch_optional(&db.0)
+impl Database {
+    pub async fn subject(&self, id: i64) -> sqlx::Result<Option<Subject>> {
+        sqlx::query_as!(
+            Subject,
+            "SELECT id, abbr, name FROM Subjects WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await
+    }
+
+    pub async fn subjects(&self) -> sqlx::Result<Vec<Subject>> {
+        sqlx::query_as!(Subject, "SELECT id, abbr, name FROM Subjects ORDER BY name")
+            .fetch_all(&self.0)
             .await
     }
 
-    pub(crate) async fn insert(&self, db: &Database) -> sqlx::Result<()> {
-        sqlx::query_as!(
-            Self,
-            "INSERT OR REPLACE INTO Subjects(id, abbr, name) VALUES (?, ?, ?)",
-            self.id,
-            self.abbr,
-            self.name
+    /// Progress of every subject of the group, teacher or auditorium (or all the stored events)
+    /// as of the `now` timestamp, ordered by subject id.
+    pub async fn subject_progress(
+        &self,
+        of: Option<TimetableKind>,
+        now: i64,
+    ) -> sqlx::Result<Vec<SubjectProgress>> {
+        let (group, teacher, auditorium) = match of {
+            Some(TimetableKind::Group(id)) => (Some(id), None, None),
+            Some(TimetableKind::Teacher(id)) => (None, Some(id), None),
+            Some(TimetableKind::Auditorium(id)) => (None, None, Some(id)),
+            None => (None, None, None),
+        };
+        let rows = sqlx::query!(
+            r#"SELECT e.subject_id, e.kind, e.kind_label,
+                COUNT(*) AS "total!: i64",
+                SUM(e.ends_at <= ?1) AS "completed!: i64",
+                SUM((e.ends_at - e.starts_at) / ?2) AS "hours!: i64",
+                SUM(CASE WHEN e.ends_at <= ?1 THEN (e.ends_at - e.starts_at) / ?2 ELSE 0 END) AS "completed_hours!: i64"
+            FROM Events e
+            WHERE (?3 IS NULL OR EXISTS (
+                    SELECT 1 FROM EventGroups eg WHERE eg.event_id = e.id AND eg.group_id = ?3))
+              AND (?4 IS NULL OR EXISTS (
+                    SELECT 1 FROM EventTeachers et WHERE et.event_id = e.id AND et.teacher_id = ?4))
+              AND (?5 IS NULL OR e.auditorium_id = ?5)
+            GROUP BY e.subject_id, e.kind, e.kind_label
+            ORDER BY e.subject_id"#,
+            now,
+            ACADEMIC_HOUR,
+            group,
+            teacher,
+            auditorium,
         )
-        .execute(&db.0)
+        .fetch_all(&self.0)
         .await?;
+
+        let mut subjects: Vec<SubjectProgress> = Vec::new();
+        for row in rows {
+            if subjects.last().is_none_or(|s| s.subject != row.subject_id) {
+                subjects.push(SubjectProgress {
+                    subject: row.subject_id,
+                    ..Default::default()
+                });
+            }
+            let count = |n: i64| u32::try_from(n).unwrap_or_default();
+            let progress = Progress {
+                total: count(row.total),
+                completed: count(row.completed),
+                hours: count(row.hours),
+                completed_hours: count(row.completed_hours),
+            };
+            if let Some(subject) = subjects.last_mut() {
+                // kind and label are grouped by, so every kind comes once
+                let kind = kind_from_columns(row.kind, row.kind_label);
+                subject.kinds.insert(kind, progress);
+            }
+        }
+        Ok(subjects)
+    }
+
+    pub async fn insert_subjects<'a>(
+        &self,
+        subjects: impl IntoIterator<Item = &'a Subject>,
+    ) -> sqlx::Result<()> {
+        let mut tx = self.0.begin().await?;
+        for subject in subjects {
+            insert(&mut tx, subject).await?;
+        }
+        tx.commit().await
+    }
+}
+
+pub(super) async fn insert(conn: &mut SqliteConnection, subject: &Subject) -> sqlx::Result<()> {
+    sqlx::query!(
+        "INSERT OR REPLACE INTO Subjects(id, abbr, name) VALUES (?, ?, ?)",
+        subject.id,
+        subject.abbr,
+        subject.name
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use schedule_model::{Auditorium, Event, EventKind, Group, Timetable};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[sqlx::test]
+    async fn subject_progress() -> sqlx::Result<()> {
+        let db = Database::in_memory().await?;
+
+        db.insert_auditoriums(&[Auditorium {
+            id: 1,
+            name: "287".into(),
+            floor: 2,
+            power: true,
+            building: "г".into(),
+        }])
+        .await?;
+        db.insert_groups(&[1, 2].map(|id| Group {
+            id,
+            name: format!("ПЗПІ-23-{id}"),
+            direction_id: None,
+            speciality_id: None,
+        }))
+        .await?;
+
+        let timetable = Timetable::new(
+            [
+                Event::test(1, DAY).groups([1]),
+                Event::test(2, 2 * DAY).groups([1]),
+                Event::test(3, 3 * DAY)
+                    .kind(EventKind::Unknown("Сем".into()))
+                    .groups([1]),
+                Event::test(4, 4 * DAY)
+                    .subject(2)
+                    .kind(EventKind::Exam)
+                    .groups([1]),
+                Event::test(5, 5 * DAY)
+                    .subject(2)
+                    .kind(EventKind::Exam)
+                    .groups([2]),
+            ],
+            [1, 2].map(|id| Subject {
+                id,
+                abbr: format!("S{id}"),
+                name: format!("Subject {id}"),
+            }),
+        );
+        db.insert_timetable(&timetable).await?;
+
+        let now = 2 * DAY + 95 * 60;
+        assert_eq!(
+            db.subject_progress(None, now).await?,
+            timetable.progress(now)
+        );
+
+        let group = db
+            .subject_progress(Some(TimetableKind::Group(2)), now)
+            .await?;
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0].subject, 2);
+        assert_eq!(group[0].total().total, 1);
+
+        db.close().await;
+
         Ok(())
     }
 }
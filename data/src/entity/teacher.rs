@@ -1,40 +1,122 @@
 use crate::Database;
 
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub struct Teacher {
-    pub id: i64,
-    pub name: String,
-    // pub short_name: String,
-}
+use schedule_model::{Teacher, TimetableKind, analysis::Workload, chrono::NaiveDate};
+use sqlx::SqliteConnection;
+
+impl Database {
+    pub async fn teacher(&self, id: i64) -> sqlx::Result<Option<Teacher>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, abbr, department_id FROM Teachers WHERE id = ?",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await?
+        .map(|t| Teacher {
+            id: t.id,
+            abbr: t.abbr,
+            name: t.name,
+            department_id: t.department_id.and_then(|id| id.try_into().ok()),
+        }))
+    }
+
+    pub async fn teachers(&self) -> sqlx::Result<Vec<Teacher>> {
+        Ok(
+            sqlx::query!("SELECT id, name, abbr, department_id FROM Teachers ORDER BY name")
+                .fetch_all(&self.0)
+                .await?
+                .into_iter()
+                .map(|t| Teacher {
+                    id: t.id,
+                    abbr: t.abbr,
+                    name: t.name,
+                    department_id: t.department_id.and_then(|id| id.try_into().ok()),
+                })
+                .collect(),
+        )
+    }
 
-impl Teacher {
-    pub async fn fetch(db: &Database, id: i64) -> sqlx::Result<Option<Self>> {
-        sqlx::query_as!(Self, "SELECT * FROM Teachers WHERE id = ?", id)
-            .fetch_optional(&db.0)
-            .await
+    /// Workload of the teacher by the stored events between the local dates (inclusive).
+    pub async fn workload(
+        &self,
+        id: i64,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> sqlx::Result<Workload> {
+        let timetable = self.timetable_of([TimetableKind::Teacher(id)]).await?;
+        Ok(Workload::of(&timetable, id, from, to))
     }
 
-    pub async fn fetch_by_event(db: &Database, id: i64) -> sqlx::Result<Vec<Self>> {
-        sqlx::query_as!(
-            Self,
-            "SELECT id, name FROM Teachers
+    pub async fn teachers_by_event(&self, id: i64) -> sqlx::Result<Vec<Teacher>> {
+        Ok(sqlx::query!(
+            "SELECT id, name, abbr, department_id FROM Teachers
             JOIN EventTeachers ON teacher_id = id
             WHERE event_id = ?",
             id
         )
-        .fetch_all(&db.0)
-        .await
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(|t| Teacher {
+            id: t.id,
+            abbr: t.abbr,
+            name: t.name,
+            department_id: t.department_id.and_then(|id| id.try_into().ok()),
+        })
+        .collect())
     }
 
-    pub(crate) async fn insert(&self, db: &Database) -> sqlx::Result<()> {
-        sqlx::query_as!(
-            Self,
-            "INSERT OR REPLACE INTO Teachers VALUES (?, ?)",
-            self.id,
-            self.name
-        )
-        .execute(&db.0)
-        .await?;
+    pub async fn insert_teachers<'a>(
+        &self,
+        teachers: impl IntoIterator<Item = &'a Teacher>,
+    ) -> sqlx::Result<()> {
+        let mut tx = self.0.begin().await?;
+        for teacher in teachers {
+            insert(&mut tx, teacher).await?;
+        }
+        tx.commit().await
+    }
+}
+
+async fn insert(conn: &mut SqliteConnection, teacher: &Teacher) -> sqlx::Result<()> {
+    sqlx::query!(
+        "INSERT OR REPLACE INTO Teachers(id, name, abbr, department_id) VALUES (?, ?, ?, ?)",
+        teacher.id,
+        teacher.name,
+        teacher.abbr,
+        teacher.department_id,
+    )
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test]
+    async fn round_trip() -> sqlx::Result<()> {
+        let db = Database::in_memory().await?;
+        let teachers = [
+            Teacher::new(1, "Шевченко Тарас Григорович".into(), Some(3)),
+            // Only the short name is known upstream
+            Teacher::with_short_name(2, " ".into(), "Українка Л.".into(), None),
+            Teacher {
+                id: 3,
+                abbr: "Франко І.".into(),
+                name: String::new(),
+                department_id: None,
+            },
+        ];
+        db.insert_teachers(&teachers).await?;
+
+        for teacher in teachers {
+            let stored = db.teacher(teacher.id).await?.unwrap();
+            assert_eq!(
+                (stored.abbr, stored.name, stored.department_id),
+                (teacher.abbr, teacher.name, teacher.department_id)
+            );
+        }
         Ok(())
     }
 }
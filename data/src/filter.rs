@@ -1,5 +1,6 @@
-use crate::EventKind;
+use crate::kind_code;
 
+use schedule_model::{EventKind, ONLINE_AUDITORIUMS};
 use std::{collections::BTreeSet, fmt::Write};
 
 /// ```rust
@@ -14,6 +15,7 @@ pub struct Filter {
     auditoriums: BTreeSet<i64>,
     groups: BTreeSet<i64>,
     teachers: BTreeSet<i64>,
+    online: Option<bool>,
 }
 
 impl Filter {
@@ -46,6 +48,13 @@ impl Filter {
         self.teachers = BTreeSet::from_iter(iter);
         self
     }
+    /// Only the online (or only the in-person) events: in the stored online auditoriums
+    /// or the known ones, see [`ONLINE_AUDITORIUMS`].
+    #[must_use]
+    pub fn online(mut self, online: bool) -> Self {
+        self.online = Some(online);
+        self
+    }
 
     /// Should write something similar to this:
     /// ```sql
@@ -54,9 +63,10 @@ impl Filter {
     ///   JOIN EventTeachers et ON et.event_id = e.id
     /// WHERE e.auditorium_id = 1
     ///   AND e.subject_id IN (1, 2)
-    ///   AND e.kind IN (1, 2, 3, 4)
+    ///   AND (e.kind_label IN ('Сем') OR e.kind IN (1, 2, 3, 4))
     ///   AND eg.group_id IN (1, 2)
     ///   AND et.teacher_id IN (1, 2)
+    ///   AND (e.auditorium_id IN (-4) OR e.auditorium_id IN (SELECT id FROM Auditoriums WHERE online))
     /// GROUP BY e.id HAVING
     ///       COUNT(DISTINCT eg.group_id) = 2
     ///   AND COUNT(DISTINCT et.teacher_id) = 2
@@ -67,6 +77,7 @@ impl Filter {
             && self.auditoriums.is_empty()
             && self.groups.is_empty()
             && self.teachers.is_empty()
+            && self.online.is_none()
         {
             return;
         }
@@ -88,7 +99,50 @@ impl Filter {
         query.push_str("WHERE ");
 
         let mut prepend_and = false;
-        where_condition(query, &mut prepend_and, "e.kind", &self.kinds);
+        let labels: Vec<_> = self
+            .kinds
+            .iter()
+            .filter_map(|kind| match kind {
+                EventKind::Unknown(label) => Some(label),
+                _ => None,
+            })
+            .collect();
+        if labels.is_empty() {
+            where_condition(
+                query,
+                &mut prepend_and,
+                "e.kind",
+                self.kinds.iter().map(kind_code),
+            );
+        } else {
+            // The unknown kinds share a code, only their labels tell them apart
+            if prepend_and {
+                query.push_str("  AND ");
+            }
+            prepend_and = true;
+
+            query.push_str("(e.kind_label IN (");
+            for (i, label) in labels.iter().enumerate() {
+                if i > 0 {
+                    query.push_str(", ");
+                }
+                write!(query, "'{}'", label.replace('\'', "''")).unwrap();
+            }
+            query.push(')');
+
+            let known = self
+                .kinds
+                .iter()
+                .filter(|kind| !matches!(kind, EventKind::Unknown(_)));
+            for (i, kind) in known.enumerate() {
+                query.push_str(if i == 0 { " OR e.kind IN (" } else { ", " });
+                write!(query, "{}", kind_code(kind)).unwrap();
+            }
+            if labels.len() < self.kinds.len() {
+                query.push(')');
+            }
+            query.push_str(")\n");
+        }
         where_condition(query, &mut prepend_and, "e.subject_id", &self.subjects);
         where_condition(
             query,
@@ -98,6 +152,22 @@ impl Filter {
         );
         where_condition(query, &mut prepend_and, "eg.group_id", &self.groups);
         where_condition(query, &mut prepend_and, "et.teacher_id", &self.teachers);
+        if let Some(online) = self.online {
+            if prepend_and {
+                query.push_str("  AND ");
+            }
+            if !online {
+                query.push_str("NOT ");
+            }
+            query.push_str("(e.auditorium_id IN (");
+            for (i, (id, _)) in ONLINE_AUDITORIUMS.iter().enumerate() {
+                if i > 0 {
+                    query.push_str(", ");
+                }
+                write!(query, "{id}").unwrap();
+            }
+            query.push_str(") OR e.auditorium_id IN (SELECT id FROM Auditoriums WHERE online))\n");
+        }
 
         if self.groups.is_empty() && self.teachers.is_empty() {
             return;
@@ -171,7 +241,8 @@ mod tests {
                 .subjects([7, 8, 9])
                 .auditoriums([42])
                 .groups([1, 2, 3])
-                .teachers([4, 5, 6]),
+                .teachers([4, 5, 6])
+                .online(true),
             Filter {
                 kinds: BTreeSet::from([
                     EventKind::Lecture,
@@ -182,6 +253,7 @@ mod tests {
                 auditoriums: BTreeSet::from([42]),
                 groups: BTreeSet::from([1, 2, 3]),
                 teachers: BTreeSet::from([4, 5, 6]),
+                online: Some(true),
             }
         );
     }
@@ -1,14 +1,18 @@
 mod entity;
+#[allow(unused_imports)]
 mod error; // TODO
 mod filter;
+mod search;
 
-pub use entity::*;
 pub use filter::*;
 
+use entity::kind_code;
+
 use std::{path::Path, time::Duration};
 
+use schedule_model::{Auditorium, TeacherName};
 use sqlx::{
-    Connection, SqlitePool,
+    Connection, SqliteConnection, SqlitePool,
     sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions},
 };
 
@@ -35,9 +39,7 @@ impl Database {
 
         // Separate single connection to avoid possible race conditions
         let mut conn = pool.acquire().await?.detach();
-        sqlx::query(include_str!("../schema.sql"))
-            .execute(&mut conn)
-            .await?;
+        init(&mut conn).await?;
         conn.close().await?;
 
         Ok(Self(pool))
@@ -63,14 +65,112 @@ impl Database {
             .connect_with(opt)
             .await?;
 
-        sqlx::query(include_str!("../schema.sql"))
-            .execute(&pool)
-            .await?;
+        init(&mut *pool.acquire().await?).await?;
 
         Ok(Self(pool))
     }
 }
 
+/// Of the `schema.sql` as `PRAGMA user_version`, the tables created before it have 0.
+const SCHEMA_VERSION: i64 = 3;
+
+/// Creates the tables or brings the ones of an older schema up to date,
+/// the `CREATE TABLE IF NOT EXISTS` statements keep them as they were.
+async fn init(conn: &mut SqliteConnection) -> sqlx::Result<()> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'Events')",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    if exists {
+        let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&mut *conn)
+            .await?;
+        if version < SCHEMA_VERSION {
+            let mut tx = conn.begin().await?;
+            migrate(&mut tx, version).await?;
+            tx.commit().await?;
+        }
+    }
+
+    sqlx::query(include_str!("../schema.sql"))
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query(&format!("PRAGMA user_version = {SCHEMA_VERSION}"))
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// From the `version` to [`SCHEMA_VERSION`].
+async fn migrate(conn: &mut SqliteConnection, version: i64) -> sqlx::Result<()> {
+    if version < 1 {
+        sqlx::query(
+            "ALTER TABLE Groups ADD COLUMN direction_id INTEGER;
+            ALTER TABLE Groups ADD COLUMN speciality_id INTEGER;
+            ALTER TABLE Teachers ADD COLUMN department_id INTEGER;
+            ALTER TABLE Auditoriums ADD COLUMN floor INTEGER NOT NULL DEFAULT 0;
+            ALTER TABLE Auditoriums ADD COLUMN building TEXT NOT NULL DEFAULT '';
+            ALTER TABLE Auditoriums
+                ADD COLUMN has_power BOOLEAN NOT NULL DEFAULT 0 CHECK (has_power IN (0, 1));
+            ALTER TABLE Auditoriums
+                ADD COLUMN online BOOLEAN NOT NULL DEFAULT 0 CHECK (online IN (0, 1));
+            ALTER TABLE Events ADD COLUMN kind_label TEXT;
+            ALTER TABLE Events ADD COLUMN ends_at INTEGER NOT NULL DEFAULT 0;
+            UPDATE Events SET ends_at = starts_at + duration * 60;
+            ALTER TABLE Events DROP COLUMN duration;",
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        // Derived from the names, as if fetched without them
+        let stored: Vec<(i64, String)> = sqlx::query_as("SELECT id, name FROM Auditoriums")
+            .fetch_all(&mut *conn)
+            .await?;
+        for (id, name) in stored {
+            let auditorium = Auditorium::with_name(id, name, None, false, None);
+            sqlx::query("UPDATE Auditoriums SET floor = ?, building = ?, online = ? WHERE id = ?")
+                .bind(auditorium.floor)
+                .bind(&auditorium.building)
+                .bind(auditorium.is_online())
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+        }
+    }
+    if version < 2 {
+        // Lowercase buildings, `lower()` of SQLite is only for ASCII
+        let stored: Vec<(i64, String)> = sqlx::query_as("SELECT id, building FROM Auditoriums")
+            .fetch_all(&mut *conn)
+            .await?;
+        for (id, building) in stored {
+            sqlx::query("UPDATE Auditoriums SET building = ? WHERE id = ?")
+                .bind(building.to_lowercase())
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+        }
+    }
+    if version < 3 {
+        sqlx::query("ALTER TABLE Teachers ADD COLUMN abbr TEXT NOT NULL DEFAULT ''")
+            .execute(&mut *conn)
+            .await?;
+
+        // Derived from the full names, as they were read back before
+        let stored: Vec<(i64, String)> = sqlx::query_as("SELECT id, name FROM Teachers")
+            .fetch_all(&mut *conn)
+            .await?;
+        for (id, name) in stored {
+            sqlx::query("UPDATE Teachers SET abbr = ? WHERE id = ?")
+                .bind(TeacherName::parse(&name).short())
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,11 +192,190 @@ mod tests {
         Ok(())
     }
 
+    #[sqlx::test]
+    async fn migrate_baseline() -> sqlx::Result<()> {
+        let file = "/tmp/data_migrate_baseline.sqlite3";
+        let _ = std::fs::remove_file(file);
+
+        // The tables before the schema versions
+        let opt = SqliteConnectOptions::new()
+            .filename(file)
+            .create_if_missing(true);
+        let mut conn = SqliteConnection::connect_with(&opt).await?;
+        sqlx::query(
+            "CREATE TABLE Groups (id INTEGER PRIMARY KEY, name TEXT NOT NULL) WITHOUT ROWID;
+            CREATE TABLE Teachers (id INTEGER PRIMARY KEY, name TEXT NOT NULL) WITHOUT ROWID;
+            CREATE TABLE Subjects (
+                id INTEGER PRIMARY KEY,
+                abbr TEXT UNIQUE NOT NULL,
+                name TEXT UNIQUE NOT NULL
+            ) WITHOUT ROWID;
+            CREATE TABLE Auditoriums (id INTEGER PRIMARY KEY, name TEXT UNIQUE NOT NULL) WITHOUT ROWID;
+            CREATE TABLE Events (
+                id INTEGER PRIMARY KEY,
+                subject_id INTEGER NOT NULL REFERENCES Subjects(id) ON DELETE CASCADE,
+                auditorium_id INTEGER NOT NULL REFERENCES Auditoriums(id) ON DELETE CASCADE,
+                kind INTEGER NOT NULL,
+                count INTEGER NOT NULL,
+                starts_at INTEGER NOT NULL,
+                duration INTEGER NOT NULL DEFAULT 95
+            ) WITHOUT ROWID;
+            CREATE TABLE EventGroups (
+                event_id INTEGER REFERENCES Events(id) ON DELETE CASCADE,
+                group_id INTEGER REFERENCES Groups(id) ON DELETE CASCADE,
+                PRIMARY KEY (event_id, group_id)
+            ) WITHOUT ROWID;
+            CREATE TABLE EventTeachers (
+                event_id INTEGER REFERENCES Events(id) ON DELETE CASCADE,
+                teacher_id INTEGER REFERENCES Teachers(id) ON DELETE CASCADE,
+                PRIMARY KEY (event_id, teacher_id)
+            ) WITHOUT ROWID;
+
+            INSERT INTO Groups VALUES (1, 'ПЗПІ-23-5');
+            INSERT INTO Teachers VALUES (1, 'Шевченко Тарас Григорович');
+            INSERT INTO Subjects VALUES (1, 'ВМ', 'Вища математика');
+            INSERT INTO Auditoriums VALUES (-4, 'DL_1'), (1, 'г287');
+            INSERT INTO Events VALUES (1, 1, 1, 0, 1, 1757911500, 95);
+            INSERT INTO EventGroups VALUES (1, 1);
+            INSERT INTO EventTeachers VALUES (1, 1);",
+        )
+        .execute(&mut conn)
+        .await?;
+        conn.close().await?;
+
+        let db = Database::new(file).await?;
+        let event = db.event(1).await?.unwrap();
+        assert_eq!(event.ends_at, 1757911500 + 95 * 60);
+        assert_eq!(event.groups, [1].into());
+        let auditorium = db.auditorium(1).await?.unwrap();
+        assert_eq!((auditorium.floor, auditorium.building.as_str()), (2, "г"));
+        let online: Vec<i64> = sqlx::query_scalar("SELECT id FROM Auditoriums WHERE online")
+            .fetch_all(&db.0)
+            .await?;
+        assert_eq!(online, [-4]);
+        assert_eq!(db.teacher(1).await?.unwrap().abbr, "Шевченко Т. Г.");
+
+        // As stored by the version 1
+        sqlx::query(
+            "UPDATE Auditoriums SET building = 'Г' WHERE id = 1;
+            ALTER TABLE Teachers DROP COLUMN abbr;
+            PRAGMA user_version = 1",
+        )
+        .execute(&db.0)
+        .await?;
+        db.close().await;
+        let db = Database::new(file).await?;
+        assert_eq!(db.auditorium(1).await?.unwrap().building, "г");
+        assert_eq!(db.teacher(1).await?.unwrap().abbr, "Шевченко Т. Г.");
+        db.close().await;
+
+        // Already migrated
+        let db = Database::new(file).await?;
+        let version: i64 = sqlx::query_scalar("PRAGMA user_version")
+            .fetch_one(&db.0)
+            .await?;
+        assert_eq!(version, SCHEMA_VERSION);
+        assert!(db.event(1).await?.is_some());
+        db.close().await;
+
+        let _ = std::fs::remove_file(file);
+
+        Ok(())
+    }
+
     #[sqlx::test]
     async fn open_in_memory_db() -> sqlx::Result<()> {
         Database::in_memory().await?.close().await;
         Ok(())
     }
+
+    #[sqlx::test]
+    async fn fetched_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use schedule_fetcher::Mindenit;
+        use std::collections::BTreeSet;
+
+        let groups =
+            Mindenit::parse_groups(include_str!("../../fetcher/test-data/groups.json").as_bytes())?;
+        let teachers = Mindenit::parse_teachers(
+            include_str!("../../fetcher/test-data/teachers.json").as_bytes(),
+        )?;
+        let auditoriums = Mindenit::parse_auditoriums(
+            include_str!("../../fetcher/test-data/auditoriums.json").as_bytes(),
+        )?;
+        let timetable = Mindenit::parse_timetable(
+            include_str!("../../fetcher/test-data/group-schedule.json").as_bytes(),
+        )?;
+
+        let db = Database::in_memory().await?;
+        db.insert_groups(&groups).await?;
+        db.insert_teachers(&teachers).await?;
+        db.insert_auditoriums(&auditoriums).await?;
+        db.insert_timetable(&timetable).await?;
+
+        for g in &groups {
+            let stored = db.group(g.id).await?.unwrap();
+            assert_eq!(
+                (&stored.name, stored.direction_id, stored.speciality_id),
+                (&g.name, g.direction_id, g.speciality_id)
+            );
+        }
+        for t in &teachers {
+            let stored = db.teacher(t.id).await?.unwrap();
+            assert_eq!(
+                (&stored.name, &stored.abbr, stored.department_id),
+                (&t.name, &t.abbr, t.department_id)
+            );
+        }
+        for a in &auditoriums {
+            let stored = db.auditorium(a.id).await?.unwrap();
+            assert_eq!(
+                (&stored.name, stored.floor, stored.power, &stored.building),
+                (&a.name, a.floor, a.power, &a.building)
+            );
+        }
+
+        let include = timetable
+            .groups()
+            .map(|id| Filter::new().groups([id]))
+            .collect();
+        let stored = db.timetable(include, BTreeSet::new()).await?;
+        assert_eq!(stored.len(), timetable.len());
+
+        for (stored, event) in stored.iter().zip(timetable.iter()) {
+            assert_eq!(
+                (
+                    stored.id,
+                    stored.starts_at,
+                    stored.ends_at,
+                    &stored.kind,
+                    stored.count,
+                    stored.subject,
+                    stored.auditorium,
+                    &stored.groups,
+                    &stored.teachers,
+                ),
+                (
+                    event.id,
+                    event.starts_at,
+                    event.ends_at,
+                    &event.kind,
+                    event.count,
+                    event.subject,
+                    event.auditorium,
+                    &event.groups,
+                    &event.teachers,
+                )
+            );
+        }
+        for subject in timetable.subjects() {
+            let stored = stored.subject(subject.id).unwrap();
+            assert_eq!((&stored.abbr, &stored.name), (&subject.abbr, &subject.name));
+        }
+
+        db.close().await;
+
+        Ok(())
+    }
 }
 
 // TODO: move to utils crate
@@ -0,0 +1,52 @@
+use crate::Database;
+
+use schedule_model::search::SearchIndex;
+
+impl Database {
+    /// Search index over all the stored groups, teachers, subjects and auditoriums.
+    ///
+    /// Build it once and keep it around for search as you type,
+    /// rebuild it after storing new ones.
+    pub async fn search_index(&self) -> sqlx::Result<SearchIndex> {
+        Ok(SearchIndex::new()
+            .groups(&self.groups().await?)
+            .teachers(&self.teachers().await?)
+            .subjects(&self.subjects().await?)
+            .auditoriums(&self.auditoriums().await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use schedule_model::{Group, Subject, Teacher, search::SearchEntity};
+
+    #[sqlx::test]
+    async fn search() -> sqlx::Result<()> {
+        let db = Database::in_memory().await?;
+        db.insert_groups(&[Group {
+            id: 1,
+            name: "ПЗПІ-23-5".into(),
+            direction_id: None,
+            speciality_id: None,
+        }])
+        .await?;
+        db.insert_teachers(&[Teacher::new(2, "Шевченко Тарас Григорович".into(), None)])
+            .await?;
+        db.insert_subjects(&[Subject {
+            id: 3,
+            abbr: "ООП".into(),
+            name: "Об'єктно-орієнтоване програмування".into(),
+        }])
+        .await?;
+
+        let index = db.search_index().await?;
+        let first = |query| index.search(query, 5).first().map(|h| h.entity);
+        assert_eq!(first("pzpi 23"), Some(SearchEntity::Group(1)));
+        assert_eq!(first("Шевченко Т. Г."), Some(SearchEntity::Teacher(2)));
+        assert_eq!(first("jjg"), Some(SearchEntity::Subject(3)));
+        assert_eq!(index.len(), 3);
+        Ok(())
+    }
+}
@@ -12,6 +12,8 @@ pub use mindenit::Mindenit;
 
 use schedule_model::*;
 
+pub use schedule_model::TimetableKind;
+
 pub trait Fetcher: Default {
     fn new(agent: FetcherAgent) -> Self;
     fn fetch_groups(&self) -> Result<Groups, FetcherError>;
@@ -20,13 +22,6 @@ pub trait Fetcher: Default {
     fn fetch_timetable(&self, kind: TimetableKind) -> Result<Timetable, FetcherError>;
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub enum TimetableKind {
-    Group(i64),
-    Teacher(i64),
-    Auditorium(i64),
-}
-
 pub trait FetcherExt: Fetcher {
     fn fetch_teachers_by_group(&self, id: i64) -> Result<Teachers, FetcherError>;
     fn fetch_subjects_by_group(&self, id: i64) -> Result<Subjects, FetcherError>;
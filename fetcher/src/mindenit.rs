@@ -9,6 +9,8 @@ use crate::{
     Groups, Subjects, Teachers, Timetable, TimetableKind,
 };
 
+use std::io::Read;
+
 #[derive(Clone, Debug)]
 pub struct Mindenit {
     agent: FetcherAgent,
@@ -33,12 +35,38 @@ impl Mindenit {
         R: serde::de::DeserializeOwned + TryInto<T>,
         FetcherError: From<<R as TryInto<T>>::Error>,
     {
-        Ok(serde_json::from_reader::<_, R>(self.agent.request(&format!(
+        Self::parse::<R, T>(self.agent.request(&format!(
             "{}/{}",
             self.base_url,
             endpoint.as_ref()
-        ))?)?
-        .try_into()?)
+        ))?)
+    }
+
+    fn parse<R, T>(reader: impl Read) -> Result<T, FetcherError>
+    where
+        R: serde::de::DeserializeOwned + TryInto<T>,
+        FetcherError: From<<R as TryInto<T>>::Error>,
+    {
+        Ok(serde_json::from_reader::<_, R>(reader)?.try_into()?)
+    }
+}
+
+/// Parse the responses saved to disk.
+impl Mindenit {
+    pub fn parse_groups(reader: impl Read) -> Result<Groups, FetcherError> {
+        Self::parse::<ResponseGroups, _>(reader)
+    }
+    pub fn parse_teachers(reader: impl Read) -> Result<Teachers, FetcherError> {
+        Self::parse::<ResponseTeachers, _>(reader)
+    }
+    pub fn parse_auditoriums(reader: impl Read) -> Result<Auditoriums, FetcherError> {
+        Self::parse::<ResponseAuditoriums, _>(reader)
+    }
+    pub fn parse_subjects(reader: impl Read) -> Result<Subjects, FetcherError> {
+        Self::parse::<ResponseSubjects, _>(reader)
+    }
+    pub fn parse_timetable(reader: impl Read) -> Result<Timetable, FetcherError> {
+        Self::parse::<ResponseTimetable, _>(reader)
     }
 }
 
@@ -204,8 +232,8 @@ mod tests {
     }
 
     // NOTE: Taken from parsers.rs
-    fn timetable(Timetable { events, subjects }: Timetable) {
-        let event = events.iter().next().unwrap();
+    fn timetable(timetable: Timetable) {
+        let event = timetable.iter().next().unwrap();
 
         let crate::Event {
             id,
@@ -216,7 +244,7 @@ mod tests {
             ..
         } = event;
 
-        let subject = subjects.get(subject).unwrap();
+        let subject = timetable.subject(*subject).unwrap();
 
         println!("First event: {id}");
         println!("Subject: {subject:#?}");
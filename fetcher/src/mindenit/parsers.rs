@@ -131,6 +131,20 @@ mod tests {
         ))
     }
     #[test]
+    fn auditorium_names() -> Result<(), ResponseError> {
+        let response: Response<ArrayToSet<AuditoriumRaw, Auditorium>> =
+            serde_json::from_str(include_str!("../../test-data/auditoriums.json"))?;
+        for auditorium in HashSet::try_from(response)? {
+            assert!(
+                auditorium
+                    .parsed_name()
+                    .agrees_with(auditorium.floor, &auditorium.building),
+                "{auditorium:?}"
+            );
+        }
+        Ok(())
+    }
+    #[test]
     fn groups() -> Result<(), ResponseError> {
         parse_print_first::<GroupRaw, Group>(include_str!("../../test-data/groups.json"))
     }
@@ -178,9 +192,9 @@ mod tests {
     fn timetable(data: &str) -> Result<(), ResponseError> {
         let response: ResponseTimetable = serde_json::from_str(data)?;
 
-        let Timetable { events, subjects }: Timetable = response.try_into()?;
+        let timetable: Timetable = response.try_into()?;
 
-        let event = events.iter().next().unwrap();
+        let event = timetable.iter().next().unwrap();
 
         let crate::Event {
             id,
@@ -191,7 +205,7 @@ mod tests {
             ..
         } = event;
 
-        let subject = subjects.get(subject).unwrap();
+        let subject = timetable.subject(*subject).unwrap();
 
         println!("First event: {id}");
         println!("Subject: {subject:#?}");
@@ -5,11 +5,13 @@ use crate::Auditorium;
 pub struct AuditoriumRaw {
     id: i64,
     name: String,
-    floor: i8,
+    floor: Option<i8>,
+    #[serde(default)]
     has_power: bool,
-    building_id: String,
+    building_id: Option<String>,
 }
 
+/// The floor and building omitted upstream are derived from the name.
 impl From<AuditoriumRaw> for Auditorium {
     fn from(
         AuditoriumRaw {
@@ -20,12 +22,52 @@ impl From<AuditoriumRaw> for Auditorium {
             building_id,
         }: AuditoriumRaw,
     ) -> Self {
-        Self {
-            id,
-            name,
-            floor,
-            power: has_power,
-            building: building_id,
+        Self::with_name(id, name, floor, has_power, building_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived() -> Result<(), serde_json::Error> {
+        let raw: Vec<AuditoriumRaw> = serde_json::from_str(
+            r#"[
+                {"id": 1, "name": "и-305", "floor": null, "hasPower": true, "buildingId": null},
+                {"id": 2, "name": "287", "floor": 3, "hasPower": false, "buildingId": ""}
+            ]"#,
+        )?;
+        let auditoriums: Vec<Auditorium> = raw.into_iter().map(Auditorium::from).collect();
+        assert_eq!(
+            (auditoriums[0].floor, auditoriums[0].building.as_str()),
+            (3, "и")
+        );
+        // Upstream wins
+        assert_eq!(
+            (auditoriums[1].floor, auditoriums[1].building.as_str()),
+            (3, "г")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn names_agree() -> Result<(), serde_json::Error> {
+        let raw: Vec<AuditoriumRaw> = serde_json::from_str(
+            r#"[
+                {"id": 3, "name": "И-305", "floor": 3, "hasPower": true, "buildingId": "и"},
+                {"id": 4, "name": "з-201", "floor": 2, "hasPower": true, "buildingId": "З"},
+                {"id": 5, "name": "161", "floor": 1, "hasPower": false, "buildingId": "Г"}
+            ]"#,
+        )?;
+        for auditorium in raw.into_iter().map(Auditorium::from) {
+            assert!(
+                auditorium
+                    .parsed_name()
+                    .agrees_with(auditorium.floor, &auditorium.building),
+                "{auditorium:?}"
+            );
         }
+        Ok(())
     }
 }
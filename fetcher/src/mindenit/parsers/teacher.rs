@@ -9,6 +9,7 @@ pub struct TeacherRaw {
     department_id: Option<i32>,
 }
 
+/// The short name is derived from the full one, see [`Teacher::with_short_name`].
 impl From<TeacherRaw> for Teacher {
     fn from(
         TeacherRaw {
@@ -18,11 +19,6 @@ impl From<TeacherRaw> for Teacher {
             department_id,
         }: TeacherRaw,
     ) -> Self {
-        Self {
-            id,
-            abbr: short_name,
-            name: full_name,
-            department_id,
-        }
+        Self::with_short_name(id, full_name, short_name, department_id)
     }
 }
@@ -33,10 +33,7 @@ impl<'de> Deserialize<'de> for TimetableParser {
             where
                 A: SeqAccess<'de>,
             {
-                let mut timetable = Timetable {
-                    events: HashSet::default(),
-                    subjects: HashSet::default(),
-                };
+                let mut timetable = Timetable::default();
 
                 while let Some(EventRaw {
                     id,
@@ -53,7 +50,7 @@ impl<'de> Deserialize<'de> for TimetableParser {
                     let event_groups: HashSet<i64> = groups.iter().map(|g| g.id).collect();
                     let event_teachers: HashSet<i64> = teachers.iter().map(|t| t.id).collect();
 
-                    timetable.events.insert(Event {
+                    timetable.insert_event(Event {
                         id,
                         starts_at: started_at,
                         ends_at: ended_at,
@@ -65,7 +62,7 @@ impl<'de> Deserialize<'de> for TimetableParser {
                         teachers: event_teachers,
                     });
 
-                    timetable.subjects.insert(subject.into());
+                    timetable.insert_subject(subject.into());
                 }
 
                 Ok(TimetableParser(timetable))
@@ -94,37 +91,14 @@ pub struct EventRaw {
     pub auditorium: EventAuditoriumRaw,
 }
 
-#[derive(Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub enum EventKindRaw {
-    #[serde(rename = "Лк")]
-    Lecture,
-    #[serde(rename = "Пз")]
-    PracticalWork,
-    #[serde(rename = "Лб")]
-    LaboratoryWork,
-    #[serde(rename = "Конс")]
-    Consultation,
-    #[serde(rename = "Зал")]
-    FinalTest,
-    #[serde(rename = "Екз")]
-    Exam,
-    #[serde(rename = "КП/КР")]
-    CourseWork,
-    #[serde(other)]
-    Unknown,
-}
+/// The upstream label, e.g. "Лк", unknown ones are preserved as is.
+#[derive(Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[serde(transparent)]
+pub struct EventKindRaw(String);
+
 impl From<EventKindRaw> for EventKind {
-    fn from(value: EventKindRaw) -> Self {
-        match value {
-            EventKindRaw::Lecture => Self::Lecture,
-            EventKindRaw::PracticalWork => Self::PracticalWork,
-            EventKindRaw::LaboratoryWork => Self::LaboratoryWork,
-            EventKindRaw::Consultation => Self::Consultation,
-            EventKindRaw::FinalTest => Self::FinalTest,
-            EventKindRaw::Exam => Self::Exam,
-            EventKindRaw::CourseWork => Self::CourseWork,
-            EventKindRaw::Unknown => Self::Unknown,
-        }
+    fn from(EventKindRaw(label): EventKindRaw) -> Self {
+        Self::from_label(&label)
     }
 }
 
@@ -165,3 +139,22 @@ pub struct EventAuditoriumRaw {
     pub id: i64,
     pub name: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_kind() -> Result<(), serde_json::Error> {
+        let kinds: Vec<EventKindRaw> = serde_json::from_str(r#"["Лк", "КП/КР", "Сем"]"#)?;
+        assert_eq!(
+            kinds.into_iter().map(EventKind::from).collect::<Vec<_>>(),
+            [
+                EventKind::Lecture,
+                EventKind::CourseWork,
+                EventKind::Unknown("Сем".into())
+            ]
+        );
+        Ok(())
+    }
+}
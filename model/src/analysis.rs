@@ -0,0 +1,41 @@
+//! Reports and checks over [`Timetable`](crate::Timetable)s.
+
+mod conflict;
+#[cfg(feature = "chrono")]
+mod days;
+mod free_auditoriums;
+#[cfg(feature = "chrono")]
+mod free_slots;
+#[cfg(feature = "chrono")]
+mod irregularity;
+mod progress;
+#[cfg(feature = "chrono")]
+mod series;
+mod streams;
+#[cfg(feature = "chrono")]
+mod subgroups;
+mod table;
+#[cfg(feature = "chrono")]
+mod utilization;
+#[cfg(feature = "chrono")]
+mod workload;
+
+pub use conflict::{Conflict, ConflictRules};
+#[cfg(feature = "chrono")]
+pub use days::{Day, GapRules, Summary};
+pub use free_auditoriums::{AuditoriumQuery, FreeAuditorium};
+#[cfg(feature = "chrono")]
+pub use free_slots::{FreeSlot, FreeSlotFinder};
+#[cfg(feature = "chrono")]
+pub use irregularity::{Cancellation, Irregularities, IrregularityRules, MakeUp};
+pub use progress::{ACADEMIC_HOUR, Progress, SubjectProgress, academic_hours};
+#[cfg(feature = "chrono")]
+pub use series::Series;
+pub use streams::Stream;
+#[cfg(feature = "chrono")]
+pub use subgroups::{Subgroup, SubgroupChoice, Subgroups};
+pub use table::Table;
+#[cfg(feature = "chrono")]
+pub use utilization::{Heatmap, Utilization, UtilizationReport};
+#[cfg(feature = "chrono")]
+pub use workload::Workload;
@@ -0,0 +1,230 @@
+use crate::{Event, Location, Timetable, TimetableKind};
+
+use std::collections::BTreeSet;
+
+/// Two events that take the same group, teacher or auditorium at the same time.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Conflict {
+    /// Overlapping interval.
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub of: TimetableKind,
+    /// Ids, the earlier event goes first.
+    pub events: [i64; 2],
+}
+
+/// What overlaps are fine.
+///
+/// ```rust
+/// use schedule_model::analysis::ConflictRules;
+///
+/// let rules = ConflictRules {
+///     teachers: false,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ConflictRules {
+    pub groups: bool,
+    pub teachers: bool,
+    pub auditoriums: bool,
+    /// Ignore copies of the same class, e.g. listed once per group:
+    /// same time, subject, kind and auditorium.
+    pub ignore_copies: bool,
+    /// Ignore the distance learning auditoriums: the ones of [`Self::online_auditoriums`]
+    /// and the known ones (see [`Location::of_id`]).
+    pub ignore_online: bool,
+    /// Online auditoriums besides the ones recognized by id, e.g. by
+    /// [`Auditorium::is_online`](crate::Auditorium::is_online).
+    pub online_auditoriums: BTreeSet<i64>,
+    /// Auditoriums that may host several events at once.
+    pub shared_auditoriums: BTreeSet<i64>,
+}
+
+impl Default for ConflictRules {
+    fn default() -> Self {
+        Self {
+            groups: true,
+            teachers: true,
+            auditoriums: true,
+            ignore_copies: true,
+            ignore_online: true,
+            online_auditoriums: BTreeSet::new(),
+            shared_auditoriums: BTreeSet::new(),
+        }
+    }
+}
+
+impl ConflictRules {
+    /// All the conflicts, ordered by time.
+    pub fn check(&self, timetable: &Timetable) -> Vec<Conflict> {
+        let mut kinds = Vec::new();
+        if self.groups {
+            kinds.extend(timetable.groups().map(TimetableKind::Group));
+        }
+        if self.teachers {
+            kinds.extend(timetable.teachers().map(TimetableKind::Teacher));
+        }
+        if self.auditoriums {
+            kinds.extend(
+                timetable
+                    .auditoriums()
+                    .filter(|id| !self.is_shared(*id))
+                    .map(TimetableKind::Auditorium),
+            );
+        }
+
+        let mut conflicts = Vec::new();
+        for of in kinds {
+            self.sweep(of, timetable.events_of(of), &mut conflicts);
+        }
+        conflicts.sort_unstable();
+        conflicts
+    }
+
+    fn is_shared(&self, auditorium: i64) -> bool {
+        (self.ignore_online
+            && (Location::of_id(auditorium) == Location::Online
+                || self.online_auditoriums.contains(&auditorium)))
+            || self.shared_auditoriums.contains(&auditorium)
+    }
+
+    fn sweep<'a>(
+        &self,
+        of: TimetableKind,
+        events: impl Iterator<Item = &'a Event>,
+        conflicts: &mut Vec<Conflict>,
+    ) {
+        let mut active: Vec<&Event> = Vec::new();
+
+        for event in events {
+            active.retain(|a| a.ends_at > event.starts_at);
+
+            for a in &active {
+                if self.ignore_copies && copies(a, event) {
+                    continue;
+                }
+                conflicts.push(Conflict {
+                    starts_at: event.starts_at,
+                    ends_at: a.ends_at.min(event.ends_at),
+                    of,
+                    events: [a.id, event.id],
+                });
+            }
+
+            active.push(event);
+        }
+    }
+}
+
+fn copies(a: &Event, b: &Event) -> bool {
+    a.starts_at == b.starts_at
+        && a.ends_at == b.ends_at
+        && a.subject == b.subject
+        && a.kind == b.kind
+        && a.auditorium == b.auditorium
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60;
+
+    #[test]
+    fn overlaps() {
+        let timetable = Timetable::new(
+            [
+                Event::test(1, 0).groups([1]).teachers([1]),
+                Event::test(2, 60 * MINUTE)
+                    .auditorium(2)
+                    .groups([1])
+                    .teachers([2]),
+                Event::test(3, 60 * MINUTE).groups([2]).teachers([3]),
+                Event::test(4, 155 * MINUTE).groups([3]).teachers([3]), // right after, no overlap
+            ],
+            [],
+        );
+
+        assert_eq!(
+            ConflictRules::default().check(&timetable),
+            [
+                Conflict {
+                    starts_at: 60 * MINUTE,
+                    ends_at: 95 * MINUTE,
+                    of: TimetableKind::Group(1),
+                    events: [1, 2]
+                },
+                Conflict {
+                    starts_at: 60 * MINUTE,
+                    ends_at: 95 * MINUTE,
+                    of: TimetableKind::Auditorium(1),
+                    events: [1, 3]
+                },
+            ]
+        );
+
+        let rules = ConflictRules {
+            groups: false,
+            ..Default::default()
+        };
+        assert_eq!(rules.check(&timetable).len(), 1);
+    }
+
+    #[test]
+    fn benign() {
+        let timetable = Timetable::new(
+            [
+                // The same lecture listed once per group
+                Event::test(1, 0).groups([1]).teachers([1]),
+                Event::test(2, 0).groups([2]).teachers([1]),
+                // Online classes of different groups
+                Event::test(3, 0).auditorium(-4).groups([3]).teachers([3]),
+                Event::test(4, 0).auditorium(-4).groups([4]).teachers([4]),
+            ],
+            [],
+        );
+        assert_eq!(ConflictRules::default().check(&timetable), []);
+
+        let strict = ConflictRules {
+            ignore_copies: false,
+            ignore_online: false,
+            ..Default::default()
+        };
+        let conflicts = strict.check(&timetable);
+        assert_eq!(
+            conflicts.iter().map(|c| c.of).collect::<Vec<_>>(),
+            [
+                TimetableKind::Teacher(1),
+                TimetableKind::Auditorium(-4),
+                TimetableKind::Auditorium(1)
+            ]
+        );
+
+        let shared = ConflictRules {
+            ignore_copies: false,
+            shared_auditoriums: BTreeSet::from([1]),
+            ..Default::default()
+        };
+        assert_eq!(shared.check(&timetable).len(), 1); // only the teacher
+
+        // Online by the name
+        let zoom = Timetable::new(
+            [
+                Event::test(5, 0).auditorium(9).groups([5]).teachers([5]),
+                Event::test(6, 0).auditorium(9).groups([6]).teachers([6]),
+            ],
+            [],
+        );
+        let rules = ConflictRules {
+            ignore_copies: false,
+            ..Default::default()
+        };
+        assert_eq!(rules.check(&zoom).len(), 1);
+        let online = ConflictRules {
+            online_auditoriums: BTreeSet::from([9]),
+            ..rules
+        };
+        assert_eq!(online.check(&zoom), []);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{Event, analysis::Table};
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, TimeDelta, Weekday};
+
+/// A day with classes.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Day {
+    pub date: NaiveDate,
+    pub first_pair: u8,
+    pub last_pair: u8,
+    /// Start of the first class.
+    pub starts_at: i64,
+    /// End of the last class.
+    pub ends_at: i64,
+    pub classes: u32,
+    /// Idle time between the classes (`starts_at`, `ends_at`), ordered.
+    pub gaps: Vec<(i64, i64)>,
+}
+
+impl Day {
+    pub fn on_campus(&self) -> TimeDelta {
+        TimeDelta::seconds(self.ends_at - self.starts_at)
+    }
+
+    pub fn idle(&self) -> TimeDelta {
+        TimeDelta::seconds(self.gaps.iter().map(|(s, e)| e - s).sum())
+    }
+
+    /// Up to `n` days with the most idle time, then the longest ones.
+    pub fn worst(days: &[Self], n: usize) -> Vec<&Self> {
+        let mut worst = Vec::from_iter(days);
+        worst.sort_by_key(|d| (std::cmp::Reverse((d.idle(), d.on_campus())), d.date));
+        worst.truncate(n);
+        worst
+    }
+
+    /// A row per day with the time in minutes.
+    pub fn table(days: &[Self]) -> Table {
+        let mut table = Table::new([
+            "date",
+            "first_pair",
+            "last_pair",
+            "classes",
+            "on_campus",
+            "gaps",
+            "idle",
+        ]);
+        for day in days {
+            table.push([
+                day.date.to_string(),
+                day.first_pair.to_string(),
+                day.last_pair.to_string(),
+                day.classes.to_string(),
+                day.on_campus().num_minutes().to_string(),
+                day.gaps.len().to_string(),
+                day.idle().num_minutes().to_string(),
+            ]);
+        }
+        table
+    }
+}
+
+/// Totals over several days.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Summary {
+    pub days: u32,
+    pub classes: u32,
+    pub gaps: u32,
+    pub on_campus: TimeDelta,
+    pub idle: TimeDelta,
+}
+
+impl Summary {
+    pub fn of<'a>(days: impl IntoIterator<Item = &'a Day>) -> Self {
+        days.into_iter().fold(Self::default(), |mut sum, day| {
+            sum.days += 1;
+            sum.classes += day.classes;
+            sum.gaps += u32::try_from(day.gaps.len()).unwrap_or(u32::MAX);
+            sum.on_campus += day.on_campus();
+            sum.idle += day.idle();
+            sum
+        })
+    }
+
+    pub fn average_on_campus(&self) -> TimeDelta {
+        self.on_campus / self.days.max(1).try_into().unwrap_or(i32::MAX)
+    }
+
+    pub fn average_idle(&self) -> TimeDelta {
+        self.idle / self.days.max(1).try_into().unwrap_or(i32::MAX)
+    }
+
+    /// Summaries by Monday of the week.
+    pub fn weeks(days: &[Day]) -> BTreeMap<NaiveDate, Self> {
+        let mut weeks = BTreeMap::<_, Vec<&Day>>::new();
+        for day in days {
+            weeks
+                .entry(day.date.week(Weekday::Mon).first_day())
+                .or_default()
+                .push(day);
+        }
+        weeks
+            .into_iter()
+            .map(|(week, days)| (week, Self::of(days)))
+            .collect()
+    }
+}
+
+/// Looks for the idle gaps ("windows") between classes.
+///
+/// ```rust
+/// use schedule_model::{Timetable, analysis::{GapRules, Summary}};
+///
+/// let timetable = Timetable::default();
+/// let days = GapRules::default().days(timetable.for_group(1));
+/// assert_eq!(Summary::of(&days).gaps, 0);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct GapRules {
+    /// Shorter breaks between classes are not gaps.
+    pub min_gap: TimeDelta,
+}
+
+impl Default for GapRules {
+    fn default() -> Self {
+        Self {
+            // longer than the big break
+            min_gap: TimeDelta::minutes(30),
+        }
+    }
+}
+
+impl GapRules {
+    /// Days of the `events` (e.g. of a group or a personal timetable), ordered by date.
+    pub fn days<'a>(&self, events: impl IntoIterator<Item = &'a Event>) -> Vec<Day> {
+        let mut events = Vec::from_iter(events);
+        events.sort();
+
+        let mut days: Vec<Day> = Vec::new();
+        for event in events {
+            let date = event.date();
+            match days.last_mut() {
+                Some(day) if day.date == date => {
+                    if TimeDelta::seconds(event.starts_at - day.ends_at) >= self.min_gap {
+                        day.gaps.push((day.ends_at, event.starts_at));
+                    }
+                    day.first_pair = day.first_pair.min(event.count);
+                    day.last_pair = day.last_pair.max(event.count);
+                    day.ends_at = day.ends_at.max(event.ends_at);
+                    day.classes += 1;
+                }
+                _ => days.push(Day {
+                    date,
+                    first_pair: event.count,
+                    last_pair: event.count,
+                    starts_at: event.starts_at,
+                    ends_at: event.ends_at,
+                    classes: 1,
+                    gaps: Vec::new(),
+                }),
+            }
+        }
+        days
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    #[test]
+    fn gaps() {
+        let events = [
+            // No gaps, the big break is not one
+            Event::test_pair(1, day(1), 3).groups([1]),
+            Event::test_pair(2, day(1), 4).groups([1]),
+            // Two gaps
+            Event::test_pair(3, day(2), 1).groups([1]),
+            Event::test_pair(4, day(2), 3).groups([1]),
+            Event::test_pair(5, day(2), 6).groups([1]),
+            Event::test_pair(6, day(9), 2).groups([1]),
+        ];
+        let days = GapRules::default().days(&events);
+
+        assert_eq!(days.len(), 3);
+        assert_eq!((days[0].first_pair, days[0].last_pair), (3, 4));
+        assert!(days[0].gaps.is_empty());
+        assert_eq!(days[0].on_campus(), TimeDelta::minutes(210));
+
+        assert_eq!(days[1].gaps.len(), 2);
+        // 09:20-11:15 and 12:50-16:40
+        assert_eq!(days[1].idle(), TimeDelta::minutes(115 + 230));
+
+        assert_eq!(Day::worst(&days, 1)[0].date, day(2));
+
+        let weeks = Summary::weeks(&days);
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[&day(1)].days, 2);
+        assert_eq!(weeks[&day(1)].gaps, 2);
+        assert_eq!(weeks[&day(8)].classes, 1);
+
+        let summary = Summary::of(&days);
+        assert_eq!(summary.average_idle(), TimeDelta::minutes(115));
+
+        let table = Day::table(&days);
+        assert_eq!(
+            table.rows[1],
+            ["2025-09-02", "1", "6", "3", "630", "2", "345"]
+        );
+    }
+}
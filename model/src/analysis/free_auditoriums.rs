@@ -0,0 +1,164 @@
+use crate::{Auditorium, Timetable};
+
+use std::cmp::{Ordering, Reverse};
+
+/// Which auditoriums to look for, `None` means any.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AuditoriumQuery {
+    pub building: Option<String>,
+    pub floor: Option<i8>,
+    pub power: Option<bool>,
+    /// Look for the distance learning ones too, they are always free.
+    pub include_online: bool,
+}
+
+/// Auditorium without events in the requested interval. Ordered by how long it stays free.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FreeAuditorium {
+    pub auditorium: Auditorium,
+    /// Start of the next event, `None` if there are no more.
+    pub free_until: Option<i64>,
+}
+
+impl Ord for FreeAuditorium {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |a: &Self| (Reverse(a.free_until.unwrap_or(i64::MAX)), a.auditorium.id);
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for FreeAuditorium {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl AuditoriumQuery {
+    pub fn matches(&self, auditorium: &Auditorium) -> bool {
+        self.building
+            .as_ref()
+            .is_none_or(|b| b.to_lowercase() == auditorium.building.to_lowercase())
+            && self.floor.is_none_or(|f| f == auditorium.floor)
+            && self.power.is_none_or(|p| p == auditorium.power)
+            && (self.include_online || !auditorium.is_online())
+    }
+
+    /// Matching `auditoriums` without events of the `timetable` between `from` and `to`,
+    /// the ones free for longer go first.
+    pub fn free<'a>(
+        &self,
+        auditoriums: impl IntoIterator<Item = &'a Auditorium>,
+        timetable: &Timetable,
+        from: i64,
+        to: i64,
+    ) -> Vec<FreeAuditorium> {
+        let mut free: Vec<_> = auditoriums
+            .into_iter()
+            .filter(|a| self.matches(a))
+            .filter_map(|a| {
+                let mut events = timetable
+                    .for_auditorium(a.id)
+                    .skip_while(|e| e.ends_at <= from);
+                match events.next() {
+                    Some(e) if e.starts_at < to => None,
+                    next => Some(FreeAuditorium {
+                        auditorium: a.clone(),
+                        free_until: next.map(|e| e.starts_at),
+                    }),
+                }
+            })
+            .collect();
+        free.sort();
+        free
+    }
+
+    /// Matching `auditoriums` free during the pair `slot` on the local `date`.
+    #[cfg(feature = "chrono")]
+    pub fn free_at_pair<'a>(
+        &self,
+        auditoriums: impl IntoIterator<Item = &'a Auditorium>,
+        timetable: &Timetable,
+        date: chrono::NaiveDate,
+        slot: &crate::PairSlot,
+    ) -> Vec<FreeAuditorium> {
+        let (from, to) = slot.on(date);
+        self.free(auditoriums, timetable, from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Event;
+
+    const HOUR: i64 = 60 * 60;
+
+    fn auditorium(id: i64, floor: i8, power: bool) -> Auditorium {
+        Auditorium {
+            id,
+            name: id.to_string(),
+            floor,
+            power,
+            building: "Головний".into(),
+        }
+    }
+
+    fn ids(free: &[FreeAuditorium]) -> Vec<(i64, Option<i64>)> {
+        free.iter()
+            .map(|f| (f.auditorium.id, f.free_until))
+            .collect()
+    }
+
+    #[test]
+    fn free() {
+        let auditoriums = [
+            auditorium(1, 1, true),
+            auditorium(2, 1, false),
+            auditorium(3, 2, true),
+            auditorium(4, 2, true),
+        ];
+        let timetable = Timetable::new(
+            [
+                Event::test(1, 0).lasting(HOUR),
+                Event::test(2, 2 * HOUR).lasting(HOUR),
+                Event::test(3, HOUR / 2).lasting(HOUR).auditorium(2), // busy
+                Event::test(4, 5 * HOUR).lasting(HOUR).auditorium(3),
+            ],
+            [],
+        );
+
+        let any = AuditoriumQuery::default();
+        assert_eq!(
+            ids(&any.free(&auditoriums, &timetable, HOUR, 2 * HOUR)),
+            [(4, None), (3, Some(5 * HOUR)), (1, Some(2 * HOUR))]
+        );
+
+        let query = AuditoriumQuery {
+            floor: Some(1),
+            power: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            ids(&query.free(&auditoriums, &timetable, HOUR, 2 * HOUR)),
+            [(1, Some(2 * HOUR))]
+        );
+
+        let elsewhere = AuditoriumQuery {
+            building: Some("Філія".into()),
+            ..Default::default()
+        };
+        assert_eq!(elsewhere.free(&auditoriums, &timetable, 0, HOUR), []);
+
+        let online = [Auditorium::online(-4).unwrap()];
+        assert_eq!(any.free(&online, &timetable, 0, HOUR), []);
+        let with_online = AuditoriumQuery {
+            include_online: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            ids(&with_online.free(&online, &timetable, 0, HOUR)),
+            [(-4, None)]
+        );
+    }
+}
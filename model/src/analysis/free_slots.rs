@@ -0,0 +1,224 @@
+use crate::{PairTables, Timetable, TimetableKind};
+
+use chrono::{Datelike, NaiveDate, NaiveTime, TimeDelta, Weekday};
+
+/// Pairs (one or several in a row) when the same participants are free.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FreeSlot {
+    pub date: NaiveDate,
+    pub first_pair: u8,
+    pub last_pair: u8,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub free: Vec<TimetableKind>,
+    pub busy: Vec<TimetableKind>,
+}
+
+/// Looks for the pairs when groups and teachers are free, e.g. for a consultation.
+///
+/// ```rust
+/// use schedule_model::{Timetable, TimetableKind, analysis::FreeSlotFinder};
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let group = Timetable::default();
+/// let teacher = Timetable::default();
+/// let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+///
+/// let slots = FreeSlotFinder::default().find(
+///     &[
+///         (TimetableKind::Group(1), &group),
+///         (TimetableKind::Teacher(2), &teacher),
+///     ],
+///     monday,
+///     monday,
+/// );
+/// assert_eq!(slots.len(), 1); // the whole day
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FreeSlotFinder {
+    pub pairs: PairTables,
+    pub weekdays: Vec<Weekday>,
+    /// Local time, the pairs outside are skipped.
+    pub working_hours: Option<(NaiveTime, NaiveTime)>,
+    pub min_duration: TimeDelta,
+    /// How many participants have to be free, all of them if `None`.
+    pub min_free: Option<usize>,
+}
+
+impl Default for FreeSlotFinder {
+    fn default() -> Self {
+        Self {
+            pairs: PairTables::default(),
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            working_hours: None,
+            min_duration: TimeDelta::zero(),
+            min_free: None,
+        }
+    }
+}
+
+impl FreeSlotFinder {
+    /// Free slots between the local dates (inclusive), the ones where more
+    /// participants are free go first, then ordered by time.
+    ///
+    /// Each participant is looked up in its timetable, it may be the same one for all of them.
+    pub fn find(
+        &self,
+        participants: &[(TimetableKind, &Timetable)],
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Vec<FreeSlot> {
+        let busy: Vec<(TimetableKind, Vec<(i64, i64)>)> = participants
+            .iter()
+            .map(|(kind, timetable)| {
+                let intervals = timetable
+                    .events_of(*kind)
+                    .map(|e| (e.starts_at, e.ends_at))
+                    .collect();
+                (*kind, intervals)
+            })
+            .collect();
+        let min_free = self.min_free.unwrap_or(participants.len());
+
+        let mut slots: Vec<FreeSlot> = Vec::new();
+
+        for date in from.iter_days().take_while(|d| *d <= to) {
+            if !self.weekdays.contains(&date.weekday()) {
+                continue;
+            }
+
+            let mut day: Vec<FreeSlot> = Vec::new();
+            for slot in self.pairs.for_date(date).slots() {
+                if let Some((start, end)) = self.working_hours
+                    && (slot.starts < start || slot.ends > end)
+                {
+                    continue;
+                }
+
+                let (starts_at, ends_at) = slot.on(date);
+                let (free, taken): (Vec<_>, Vec<_>) = busy
+                    .iter()
+                    .partition(|(_, intervals)| !overlaps(intervals, starts_at, ends_at));
+                let free: Vec<_> = free.into_iter().map(|(kind, _)| *kind).collect();
+                let taken: Vec<_> = taken.into_iter().map(|(kind, _)| *kind).collect();
+
+                if free.len() < min_free || free.is_empty() && !participants.is_empty() {
+                    continue;
+                }
+
+                match day.last_mut() {
+                    Some(last) if last.free == free && last.last_pair + 1 == slot.number => {
+                        last.last_pair = slot.number;
+                        last.ends_at = ends_at;
+                    }
+                    _ => day.push(FreeSlot {
+                        date,
+                        first_pair: slot.number,
+                        last_pair: slot.number,
+                        starts_at,
+                        ends_at,
+                        free,
+                        busy: taken,
+                    }),
+                }
+            }
+
+            slots.extend(
+                day.into_iter()
+                    .filter(|s| TimeDelta::seconds(s.ends_at - s.starts_at) >= self.min_duration),
+            );
+        }
+
+        slots.sort_by_key(|s| (std::cmp::Reverse(s.free.len()), s.starts_at));
+        slots
+    }
+}
+
+/// Whether any of the sorted by start `intervals` overlaps `from..to`.
+pub(super) fn overlaps(intervals: &[(i64, i64)], from: i64, to: i64) -> bool {
+    let started = intervals.partition_point(|(starts, _)| *starts < to);
+    intervals[..started].iter().any(|(_, ends)| *ends > from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Event;
+
+    fn monday() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+    }
+
+    fn pairs(slots: &[FreeSlot]) -> Vec<(u8, u8, usize)> {
+        slots
+            .iter()
+            .map(|s| (s.first_pair, s.last_pair, s.free.len()))
+            .collect()
+    }
+
+    #[test]
+    fn everyone_free() {
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, monday(), 1).groups([1]),
+                Event::test_pair(2, monday(), 2).groups([2]),
+            ],
+            [],
+        );
+        let participants = [
+            (TimetableKind::Group(1), &timetable),
+            (TimetableKind::Group(2), &timetable),
+        ];
+
+        let finder = FreeSlotFinder {
+            working_hours: Some((
+                NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            )),
+            ..Default::default()
+        };
+        assert_eq!(
+            pairs(&finder.find(&participants, monday(), monday())),
+            [(3, 5, 2)]
+        );
+
+        // Weekend
+        let sunday = monday().pred_opt().unwrap();
+        assert_eq!(finder.find(&participants, sunday, sunday), []);
+    }
+
+    #[test]
+    fn ranked() {
+        let group = Timetable::new([Event::test_pair(1, monday(), 1).groups([1])], []);
+        let other = Timetable::new([Event::test_pair(2, monday(), 2).groups([2])], []);
+        let participants = [
+            (TimetableKind::Group(1), &group),
+            (TimetableKind::Group(2), &other),
+        ];
+
+        let finder = FreeSlotFinder {
+            min_free: Some(1),
+            min_duration: TimeDelta::minutes(95),
+            ..Default::default()
+        };
+        let slots = finder.find(&participants, monday(), monday());
+        assert_eq!(pairs(&slots), [(3, 8, 2), (1, 1, 1), (2, 2, 1)]);
+        assert_eq!(slots[1].busy, [TimetableKind::Group(1)]);
+
+        let long = FreeSlotFinder {
+            min_duration: TimeDelta::hours(4),
+            ..finder
+        };
+        assert_eq!(
+            pairs(&long.find(&participants, monday(), monday())),
+            [(3, 8, 2)]
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use crate::{Event, Holidays, Timetable, analysis::Series};
+
+use chrono::NaiveDate;
+
+/// Classes that break the regular pattern of the timetable.
+#[derive(Clone, Debug, Default)]
+pub struct Irregularities {
+    /// Ordered by date.
+    pub cancelled: Vec<Cancellation>,
+    /// Ordered by the start.
+    pub make_ups: Vec<MakeUp>,
+}
+
+/// A class of a regular series that doesn't take place.
+#[derive(Clone, Debug)]
+pub struct Cancellation {
+    pub date: NaiveDate,
+    /// The removed event if known, otherwise the first occurrence of the series.
+    pub event: Event,
+    /// The event was removed or moved to another date since the previous snapshot.
+    pub confirmed: bool,
+}
+
+/// A one-off class of a subject that has a regular series, probably a make-up session.
+#[derive(Clone, Debug)]
+pub struct MakeUp {
+    pub event: Event,
+    /// The nearest cancellation of the same class.
+    pub replaces: Option<NaiveDate>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IrregularityRules {
+    /// How many classes a series needs to be regular.
+    pub min_occurrences: usize,
+    /// Missing classes on days off are expected.
+    pub holidays: Holidays,
+}
+
+impl Default for IrregularityRules {
+    fn default() -> Self {
+        Self {
+            min_occurrences: 3,
+            holidays: Holidays::default(),
+        }
+    }
+}
+
+impl IrregularityRules {
+    /// Look for probable cancellations and make-up sessions in the `timetable`,
+    /// the `previous` snapshot confirms the cancellations.
+    pub fn check(&self, timetable: &Timetable, previous: Option<&Timetable>) -> Irregularities {
+        let (regular, one_off): (Vec<_>, Vec<_>) = Series::detect(timetable)
+            .into_iter()
+            .partition(|s| s.ids.len() >= self.min_occurrences);
+
+        let diff = previous.map(|previous| previous.diff(timetable));
+        let mut cancelled: Vec<Cancellation> = diff
+            .map(|diff| {
+                let moved = diff
+                    .modified
+                    .into_iter()
+                    .filter(|m| m.old.date() != m.new.date())
+                    .map(|m| m.old);
+                diff.removed.into_iter().chain(moved).collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .map(|event| Cancellation {
+                date: event.date(),
+                event,
+                confirmed: true,
+            })
+            .collect();
+
+        for series in &regular {
+            for date in &series.exceptions {
+                let known = cancelled
+                    .iter()
+                    .any(|c| c.date == *date && same_class(&c.event, &series.event));
+                if !known && !self.holidays.is_day_off(*date) {
+                    cancelled.push(Cancellation {
+                        date: *date,
+                        event: series.event.clone(),
+                        confirmed: false,
+                    });
+                }
+            }
+        }
+        cancelled.sort_by_key(|c| (c.date, c.event.starts_at, c.event.id));
+
+        let mut make_ups: Vec<_> = one_off
+            .into_iter()
+            .filter(|s| regular.iter().any(|r| same_class(&r.event, &s.event)))
+            .flat_map(|s| s.expand().collect::<Vec<_>>())
+            .map(|event| {
+                let replaces = cancelled
+                    .iter()
+                    .filter(|c| same_class(&c.event, &event))
+                    .min_by_key(|c| (c.date - event.date()).num_days().abs())
+                    .map(|c| c.date);
+                MakeUp { event, replaces }
+            })
+            .collect();
+        make_ups.sort_by_key(|m| (m.event.starts_at, m.event.id));
+
+        Irregularities {
+            cancelled,
+            make_ups,
+        }
+    }
+}
+
+/// The same subject and kind for at least one of the groups.
+fn same_class(a: &Event, b: &Event) -> bool {
+    a.subject == b.subject
+        && a.kind == b.kind
+        && (!a.groups.is_disjoint(&b.groups) || a.groups.is_empty() && b.groups.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::EventKind;
+
+    use chrono::TimeDelta;
+
+    fn week(n: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, 1).unwrap() + TimeDelta::weeks(n)
+    }
+
+    /// Monday labs without the 3rd week, made up on Thursday.
+    fn timetable() -> Timetable {
+        Timetable::new(
+            [
+                Event::test_pair(1, week(0), 1)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([1]),
+                Event::test_pair(2, week(1), 1)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([1]),
+                Event::test_pair(4, week(3), 1)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([1]),
+                Event::test_pair(5, week(4), 1)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([1]),
+                Event::test_pair(6, week(2) + TimeDelta::days(3), 5)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([1]),
+            ],
+            [],
+        )
+    }
+
+    #[test]
+    fn gaps() {
+        let found = IrregularityRules::default().check(&timetable(), None);
+
+        assert_eq!(found.cancelled.len(), 1);
+        assert_eq!(found.cancelled[0].date, week(2));
+        assert!(!found.cancelled[0].confirmed);
+
+        assert_eq!(found.make_ups.len(), 1);
+        assert_eq!(found.make_ups[0].event.id, 6);
+        assert_eq!(found.make_ups[0].replaces, Some(week(2)));
+
+        let mut rules = IrregularityRules::default();
+        rules.holidays.extra.insert(week(2));
+        let found = rules.check(&timetable(), None);
+        assert!(found.cancelled.is_empty());
+        assert_eq!(found.make_ups[0].replaces, None);
+    }
+
+    #[test]
+    fn confirmed() {
+        let mut previous = timetable();
+        previous.insert_event(
+            Event::test_pair(3, week(2), 1)
+                .kind(EventKind::LaboratoryWork)
+                .groups([1])
+                .teachers([1]),
+        );
+        previous.insert_event(
+            Event::test_pair(7, week(5), 1)
+                .kind(EventKind::LaboratoryWork)
+                .groups([1])
+                .teachers([1]),
+        ); // the last one is gone too
+
+        let found = IrregularityRules::default().check(&timetable(), Some(&previous));
+        assert_eq!(
+            found
+                .cancelled
+                .iter()
+                .map(|c| (c.event.id, c.date, c.confirmed))
+                .collect::<Vec<_>>(),
+            [(3, week(2), true), (7, week(5), true)]
+        );
+    }
+}
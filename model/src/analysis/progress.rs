@@ -0,0 +1,158 @@
+use crate::{Event, EventKind, Timetable};
+
+use std::collections::BTreeMap;
+
+/// Length of an academic hour in seconds, a pair is two of them.
+pub const ACADEMIC_HOUR: i64 = 45 * 60;
+
+/// How many classes (and academic hours of them) there are and how many have ended.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Progress {
+    pub total: u32,
+    pub completed: u32,
+    pub hours: u32,
+    pub completed_hours: u32,
+}
+
+impl Progress {
+    pub fn remaining(&self) -> u32 {
+        self.total - self.completed
+    }
+
+    pub fn remaining_hours(&self) -> u32 {
+        self.hours - self.completed_hours
+    }
+
+    /// Completed share of the hours, from 0 to 100.
+    pub fn percent(&self) -> f64 {
+        if self.hours == 0 {
+            return 0.0;
+        }
+        f64::from(self.completed_hours) * 100.0 / f64::from(self.hours)
+    }
+
+    /// Count the `event`, it's completed if it ends by `now`.
+    pub fn add(&mut self, event: &Event, now: i64) {
+        let hours = academic_hours(event.ends_at - event.starts_at);
+        let completed = event.ends_at <= now;
+
+        self.total += 1;
+        self.hours += hours;
+        if completed {
+            self.completed += 1;
+            self.completed_hours += hours;
+        }
+    }
+}
+
+impl std::ops::Add for Progress {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            total: self.total + other.total,
+            completed: self.completed + other.completed,
+            hours: self.hours + other.hours,
+            completed_hours: self.completed_hours + other.completed_hours,
+        }
+    }
+}
+
+impl std::iter::Sum for Progress {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, p| sum + p)
+    }
+}
+
+/// Progress of a subject by kind of classes.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct SubjectProgress {
+    pub subject: i64,
+    pub kinds: BTreeMap<EventKind, Progress>,
+}
+
+impl SubjectProgress {
+    pub fn total(&self) -> Progress {
+        self.kinds.values().copied().sum()
+    }
+}
+
+/// Whole academic hours in `seconds`, the break inside a pair is not counted.
+pub fn academic_hours(seconds: i64) -> u32 {
+    u32::try_from(seconds.max(0) / ACADEMIC_HOUR).unwrap_or(u32::MAX)
+}
+
+impl Timetable {
+    /// Progress of every subject as of the `now` timestamp, ordered by subject id.
+    ///
+    /// ```rust
+    /// use schedule_model::{analysis::Progress, Timetable};
+    ///
+    /// let timetable = Timetable::default();
+    /// let semester: Progress = timetable.progress(0).iter().map(|s| s.total()).sum();
+    /// assert_eq!(semester.percent(), 0.0);
+    /// ```
+    pub fn progress(&self, now: i64) -> Vec<SubjectProgress> {
+        let mut subjects = BTreeMap::<i64, SubjectProgress>::new();
+        for event in self.iter() {
+            subjects
+                .entry(event.subject)
+                .or_insert_with(|| SubjectProgress {
+                    subject: event.subject,
+                    ..Default::default()
+                })
+                .kinds
+                .entry(event.kind.clone())
+                .or_default()
+                .add(event, now);
+        }
+        subjects.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60;
+    const DAY: i64 = 24 * 60 * MINUTE;
+
+    #[test]
+    fn progress() {
+        let timetable = Timetable::new(
+            [
+                Event::test(1, DAY),
+                Event::test(2, 2 * DAY),
+                Event::test(3, 3 * DAY).kind(EventKind::LaboratoryWork),
+                Event::test(4, 4 * DAY).kind(EventKind::LaboratoryWork),
+                Event::test(5, 5 * DAY)
+                    .subject(2)
+                    .kind(EventKind::Exam)
+                    .lasting(180 * MINUTE),
+            ],
+            [],
+        );
+
+        let progress = timetable.progress(3 * DAY);
+        assert_eq!(progress.len(), 2);
+
+        let first = &progress[0];
+        assert_eq!(
+            first.kinds[&EventKind::Lecture],
+            Progress {
+                total: 2,
+                completed: 2,
+                hours: 4,
+                completed_hours: 4
+            }
+        );
+        assert_eq!(first.kinds[&EventKind::LaboratoryWork].remaining(), 2);
+        assert_eq!(first.total().percent(), 50.0);
+
+        let exam = progress[1].total();
+        assert_eq!((exam.hours, exam.remaining_hours()), (4, 4));
+
+        let semester: Progress = progress.iter().map(|s| s.total()).sum();
+        assert_eq!(semester.percent(), 100.0 * 4.0 / 12.0);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    Event, EventKind, Timetable,
+    time::{KYIV, local_timestamp},
+};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{NaiveDate, NaiveTime, TimeDelta, Weekday};
+
+/// The same class repeating every week or every other week.
+///
+/// ```rust
+/// use schedule_model::{Timetable, analysis::Series};
+///
+/// let timetable = Timetable::default();
+/// for series in Series::detect(&timetable) {
+///     println!("{}", series.rrule());
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Series {
+    /// The first occurrence, the rest differ only in id and date.
+    pub event: Event,
+    /// In weeks.
+    pub interval: u32,
+    pub last: NaiveDate,
+    /// Dates that follow the pattern but have no class.
+    pub exceptions: BTreeSet<NaiveDate>,
+    /// Ids of the occurrences in the order of [`Series::dates`].
+    pub ids: Vec<i64>,
+}
+
+/// What has to be the same for the events of a series.
+#[derive(Eq, PartialEq, Ord, PartialOrd)]
+struct Key {
+    weekday: u32,
+    starts: NaiveTime,
+    ends: NaiveTime,
+    count: u8,
+    subject: i64,
+    kind: EventKind,
+    auditorium: i64,
+    groups: BTreeSet<i64>,
+    teachers: BTreeSet<i64>,
+}
+
+impl Key {
+    fn of(event: &Event) -> Self {
+        Self {
+            weekday: event.weekday().num_days_from_monday(),
+            starts: event.local_start().time(),
+            ends: event.local_end().time(),
+            count: event.count,
+            subject: event.subject,
+            kind: event.kind.clone(),
+            auditorium: event.auditorium,
+            groups: event.groups.iter().copied().collect(),
+            teachers: event.teachers.iter().copied().collect(),
+        }
+    }
+}
+
+impl Series {
+    /// Group the events into series, ordered by the first occurrence.
+    ///
+    /// Every event ends up in exactly one series, the ones that don't repeat are series of one.
+    /// Copies of a class on the same date go to separate series.
+    pub fn detect(timetable: &Timetable) -> Vec<Self> {
+        let mut similar: BTreeMap<Key, Vec<&Event>> = BTreeMap::new();
+        for event in timetable.iter() {
+            similar.entry(Key::of(event)).or_default().push(event);
+        }
+
+        let mut series = Vec::new();
+        for mut events in similar.into_values() {
+            while !events.is_empty() {
+                let mut dates = BTreeSet::new();
+                let (occurrences, copies) =
+                    events.into_iter().partition(|e| dates.insert(e.date()));
+                series.push(Self::from_occurrences(occurrences));
+                events = copies;
+            }
+        }
+        series.sort_by_key(|s| (s.event.starts_at, s.event.id));
+        series
+    }
+
+    /// `events` are ordered by time and on different dates.
+    fn from_occurrences(events: Vec<&Event>) -> Self {
+        let dates: Vec<NaiveDate> = events.iter().map(|e| e.date()).collect();
+        let biweekly =
+            dates.len() > 1 && dates.windows(2).all(|w| (w[1] - w[0]).num_days() % 14 == 0);
+
+        let mut series = Self {
+            event: events[0].clone(),
+            interval: if biweekly { 2 } else { 1 },
+            last: dates[dates.len() - 1],
+            exceptions: BTreeSet::new(),
+            ids: events.iter().map(|e| e.id).collect(),
+        };
+        let present = BTreeSet::from_iter(dates);
+        series.exceptions = series.pattern().filter(|d| !present.contains(d)).collect();
+        series
+    }
+
+    pub fn first(&self) -> NaiveDate {
+        self.event.date()
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        self.event.weekday()
+    }
+
+    /// Every date of the pattern, including the exceptions.
+    fn pattern(&self) -> impl Iterator<Item = NaiveDate> {
+        let step = TimeDelta::weeks(self.interval.into());
+        let last = self.last;
+        std::iter::successors(Some(self.first()), move |d| Some(*d + step))
+            .take_while(move |d| *d <= last)
+    }
+
+    /// Dates of the occurrences.
+    pub fn dates(&self) -> impl Iterator<Item = NaiveDate> {
+        self.pattern().filter(|d| !self.exceptions.contains(d))
+    }
+
+    /// RRULE (RFC 5545) of the pattern, exceptions are not included.
+    pub fn rrule(&self) -> String {
+        let day = match self.weekday() {
+            Weekday::Mon => "MO",
+            Weekday::Tue => "TU",
+            Weekday::Wed => "WE",
+            Weekday::Thu => "TH",
+            Weekday::Fri => "FR",
+            Weekday::Sat => "SA",
+            Weekday::Sun => "SU",
+        };
+        format!(
+            "FREQ=WEEKLY;INTERVAL={};BYDAY={day};UNTIL={}",
+            self.interval,
+            self.last.format("%Y%m%d")
+        )
+    }
+
+    /// The occurrences back as events.
+    pub fn expand(&self) -> impl Iterator<Item = Event> {
+        let starts = self.event.start_in(KYIV).time();
+        let ends = self.event.end_in(KYIV).time();
+        let days = (self.event.end_in(KYIV).date_naive() - self.first()).num_days();
+
+        self.dates().zip(&self.ids).map(move |(date, id)| Event {
+            id: *id,
+            starts_at: local_timestamp(date, starts),
+            ends_at: local_timestamp(date + TimeDelta::days(days), ends),
+            ..self.event.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Monday of the first week of the autumn semester.
+    fn week(n: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, 1).unwrap() + TimeDelta::weeks(n)
+    }
+
+    #[test]
+    fn weekly_and_biweekly() {
+        let mut events = vec![
+            // Weekly, no class on the 3rd week
+            Event::test_pair(1, week(0), 2).groups([1]).teachers([1]),
+            Event::test_pair(2, week(1), 2).groups([1]).teachers([1]),
+            Event::test_pair(3, week(3), 2).groups([1]).teachers([1]),
+            // Every other week, through the DST change
+            Event::test_pair(4, week(0), 2)
+                .subject(2)
+                .groups([1])
+                .teachers([1]),
+            Event::test_pair(5, week(2), 2)
+                .subject(2)
+                .groups([1])
+                .teachers([1]),
+            Event::test_pair(6, week(10), 2)
+                .subject(2)
+                .groups([1])
+                .teachers([1]),
+        ];
+        let other_auditorium = Event::test_pair(7, week(2), 2)
+            .groups([1])
+            .teachers([1])
+            .auditorium(2);
+        events.push(other_auditorium);
+
+        let timetable = Timetable::new(events.clone(), []);
+        let series = Series::detect(&timetable);
+        assert_eq!(series.len(), 3);
+
+        let weekly = &series[0];
+        assert_eq!(weekly.ids, [1, 2, 3]);
+        assert_eq!(weekly.interval, 1);
+        assert_eq!(weekly.exceptions, BTreeSet::from([week(2)]));
+        assert_eq!(
+            weekly.rrule(),
+            "FREQ=WEEKLY;INTERVAL=1;BYDAY=MO;UNTIL=20250922"
+        );
+
+        let biweekly = &series[1];
+        assert_eq!(biweekly.ids, [4, 5, 6]);
+        assert_eq!(biweekly.interval, 2);
+        assert_eq!(
+            biweekly.exceptions,
+            BTreeSet::from([week(4), week(6), week(8)])
+        );
+
+        assert_eq!(series[2].ids, [7]);
+
+        let expanded: Vec<Event> = series.iter().flat_map(|s| s.expand()).collect();
+        let mut times: Vec<_> = expanded
+            .iter()
+            .map(|e| (e.id, e.starts_at, e.ends_at))
+            .collect();
+        times.sort();
+        assert_eq!(
+            times,
+            events
+                .iter()
+                .map(|e| (e.id, e.starts_at, e.ends_at))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn copies() {
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, week(0), 2).groups([1]).teachers([1]),
+                Event::test_pair(2, week(0), 2).groups([1]).teachers([1]),
+            ],
+            [],
+        );
+        let series = Series::detect(&timetable);
+        assert_eq!(
+            series.iter().map(|s| s.ids.len()).collect::<Vec<_>>(),
+            [1, 1]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{EventKind, Timetable};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Groups (потік) attending the same lectures.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Stream {
+    pub groups: BTreeSet<i64>,
+    pub subjects: BTreeSet<i64>,
+    /// Ids, ordered.
+    pub lectures: Vec<i64>,
+}
+
+impl Stream {
+    /// Streams of the lectures with several groups, ordered by groups.
+    ///
+    /// Groups sharing any lecture of a subject make one stream of it,
+    /// the subjects with the same groups are merged.
+    pub fn detect(timetable: &Timetable) -> Vec<Self> {
+        // Groups and lectures sharing some of them, by subject
+        type Component = (BTreeSet<i64>, Vec<i64>);
+        let mut by_subject: BTreeMap<i64, Vec<Component>> = BTreeMap::new();
+        for event in timetable.iter().filter(|e| e.kind == EventKind::Lecture) {
+            let components = by_subject.entry(event.subject).or_default();
+            let mut groups = BTreeSet::from_iter(event.groups.iter().copied());
+            let mut lectures = vec![event.id];
+            components.retain_mut(|(g, l)| {
+                if g.is_disjoint(&groups) {
+                    return true;
+                }
+                groups.append(g);
+                lectures.append(l);
+                false
+            });
+            components.push((groups, lectures));
+        }
+
+        let mut streams: BTreeMap<BTreeSet<i64>, Self> = BTreeMap::new();
+        for (subject, components) in by_subject {
+            for (groups, lectures) in components.into_iter().filter(|(g, _)| g.len() > 1) {
+                let stream = streams.entry(groups.clone()).or_insert_with(|| Self {
+                    groups,
+                    subjects: BTreeSet::new(),
+                    lectures: Vec::new(),
+                });
+                stream.subjects.insert(subject);
+                stream.lectures.extend(lectures);
+            }
+        }
+        streams
+            .into_values()
+            .map(|mut stream| {
+                stream.lectures.sort_unstable();
+                stream
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Event;
+
+    const HOUR: i64 = 60 * 60;
+
+    #[test]
+    fn streams() {
+        let lecture = |id, subject, groups: &[i64]| {
+            Event::test(id, id * HOUR)
+                .subject(subject)
+                .groups(groups.iter().copied())
+        };
+        let timetable = Timetable::new(
+            [
+                lecture(1, 1, &[1, 2]),
+                lecture(2, 1, &[2, 3]),
+                lecture(3, 2, &[1, 2, 3]),
+                lecture(4, 3, &[4, 5]),
+                lecture(5, 4, &[6]),
+                Event::test(6, 0)
+                    .kind(EventKind::PracticalWork)
+                    .subject(5)
+                    .groups([4, 6]),
+            ],
+            [],
+        );
+
+        let streams = Stream::detect(&timetable);
+        assert_eq!(streams.len(), 2);
+        assert_eq!(streams[0].groups, BTreeSet::from([1, 2, 3]));
+        assert_eq!(streams[0].subjects, BTreeSet::from([1, 2]));
+        assert_eq!(streams[0].lectures, [1, 2, 3]);
+        assert_eq!(streams[1].groups, BTreeSet::from([4, 5]));
+    }
+}
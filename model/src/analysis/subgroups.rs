@@ -0,0 +1,270 @@
+use crate::{Event, EventKind, Timetable};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::{Datelike, IsoWeek};
+
+/// Part of a group taking its own classes at the same time as the other parts,
+/// told apart by the teachers (or the auditorium if there are none).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Subgroup {
+    pub group: i64,
+    pub subject: i64,
+    pub kind: EventKind,
+    /// 1-based, the one with the earlier first class goes first.
+    pub number: u8,
+    pub teachers: BTreeSet<i64>,
+    pub auditoriums: BTreeSet<i64>,
+    /// Ids of the parallel classes, ordered by time.
+    pub events: Vec<i64>,
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+enum Key {
+    Teachers(BTreeSet<i64>),
+    Auditorium(i64),
+}
+
+impl Key {
+    fn of(event: &Event) -> Self {
+        if event.teachers.is_empty() {
+            Self::Auditorium(event.auditorium)
+        } else {
+            Self::Teachers(BTreeSet::from_iter(event.teachers.iter().copied()))
+        }
+    }
+
+    fn pair(a: Self, b: Self) -> (Self, Self) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+}
+
+/// The subgroup the user is in, by subject.
+///
+/// ```rust
+/// use schedule_model::analysis::SubgroupChoice;
+///
+/// let choice = SubgroupChoice {
+///     default: Some(1),
+///     ..Default::default()
+/// }
+/// .with(42, 2);
+/// assert_eq!(choice.of(42), Some(2));
+/// assert_eq!(choice.of(7), Some(1));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct SubgroupChoice {
+    /// For the subjects not chosen explicitly, `None` keeps all the parallel classes.
+    pub default: Option<u8>,
+    pub by_subject: BTreeMap<i64, u8>,
+}
+
+impl SubgroupChoice {
+    #[must_use]
+    pub fn with(mut self, subject: i64, number: u8) -> Self {
+        self.by_subject.insert(subject, number);
+        self
+    }
+
+    pub fn of(&self, subject: i64) -> Option<u8> {
+        self.by_subject.get(&subject).copied().or(self.default)
+    }
+}
+
+/// Subgroups of a group: its parallel classes other than lectures,
+/// of the same subject and kind at the overlapping time
+/// or in the same week as the overlapping ones of the same teachers.
+///
+/// The classes of the whole group, e.g. the labs with one of the teachers
+/// in the weeks without the others, belong to no subgroup.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Subgroups {
+    pub group: i64,
+    /// Ordered by subject, kind and number.
+    pub subgroups: Vec<Subgroup>,
+    by_event: HashMap<i64, usize>,
+}
+
+impl Subgroups {
+    pub fn detect(timetable: &Timetable, group: i64) -> Self {
+        let mut classes: BTreeMap<(i64, EventKind), Vec<&Event>> = BTreeMap::new();
+        for event in timetable
+            .for_group(group)
+            .filter(|e| e.kind != EventKind::Lecture)
+        {
+            classes
+                .entry((event.subject, event.kind.clone()))
+                .or_default()
+                .push(event);
+        }
+
+        let mut subgroups = Vec::new();
+        for ((subject, kind), mut events) in classes {
+            events.sort();
+
+            // Keys that overlap somewhere and the overlapping classes
+            let mut pairs = BTreeSet::new();
+            let mut parallel = HashSet::new();
+            for (i, event) in events.iter().enumerate() {
+                for other in events[i + 1..]
+                    .iter()
+                    .take_while(|o| o.starts_at < event.ends_at)
+                {
+                    let (key, other_key) = (Key::of(event), Key::of(other));
+                    if key == other_key {
+                        continue; // listed twice
+                    }
+                    pairs.insert(Key::pair(key, other_key));
+                    parallel.extend([event.id, other.id]);
+                }
+            }
+            if pairs.is_empty() {
+                continue;
+            }
+
+            // The other classes of these keys in the weeks they both have
+            let mut weeks: BTreeMap<IsoWeek, Vec<&Event>> = BTreeMap::new();
+            for event in &events {
+                weeks
+                    .entry(event.local_start().iso_week())
+                    .or_default()
+                    .push(event);
+            }
+            for week in weeks.values() {
+                let keys = BTreeSet::from_iter(week.iter().map(|e| Key::of(e)));
+                for event in week {
+                    let key = Key::of(event);
+                    if keys
+                        .iter()
+                        .any(|other| pairs.contains(&Key::pair(key.clone(), other.clone())))
+                    {
+                        parallel.insert(event.id);
+                    }
+                }
+            }
+
+            // By the start of their first parallel class
+            let mut starts: BTreeMap<Key, i64> = BTreeMap::new();
+            for event in events.iter().filter(|e| parallel.contains(&e.id)) {
+                starts.entry(Key::of(event)).or_insert(event.starts_at);
+            }
+            let mut keys: Vec<(i64, Key)> = starts.into_iter().map(|(k, s)| (s, k)).collect();
+            keys.sort();
+            for (number, (_, key)) in (1..=u8::MAX).zip(keys) {
+                let events: Vec<&&Event> = events
+                    .iter()
+                    .filter(|e| parallel.contains(&e.id) && Key::of(e) == key)
+                    .collect();
+                subgroups.push(Subgroup {
+                    group,
+                    subject,
+                    kind: kind.clone(),
+                    number,
+                    teachers: events.iter().flat_map(|e| &e.teachers).copied().collect(),
+                    auditoriums: events.iter().map(|e| e.auditorium).collect(),
+                    events: events.iter().map(|e| e.id).collect(),
+                });
+            }
+        }
+
+        let by_event = subgroups
+            .iter()
+            .enumerate()
+            .flat_map(|(i, s)| s.events.iter().map(move |id| (*id, i)))
+            .collect();
+        Self {
+            group,
+            subgroups,
+            by_event,
+        }
+    }
+
+    pub fn subgroup_of(&self, event: i64) -> Option<&Subgroup> {
+        self.by_event.get(&event).map(|i| &self.subgroups[*i])
+    }
+
+    /// Subgroups of the subject, of any kind.
+    pub fn of_subject(&self, subject: i64) -> impl Iterator<Item = &Subgroup> {
+        self.subgroups.iter().filter(move |s| s.subject == subject)
+    }
+
+    /// Drops the classes of the other subgroups than the chosen ones.
+    pub fn filter<'a>(
+        &self,
+        events: impl IntoIterator<Item = &'a Event>,
+        choice: &SubgroupChoice,
+    ) -> impl Iterator<Item = &'a Event> {
+        events.into_iter().filter(move |e| {
+            self.subgroup_of(e.id)
+                .is_none_or(|s| choice.of(s.subject).is_none_or(|n| n == s.number))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONDAY: i64 = 1757911500;
+    const HOUR: i64 = 60 * 60;
+    const WEEK: i64 = 7 * 24 * HOUR;
+
+    #[test]
+    fn subgroups() {
+        let class = |id, starts_at, kind, subject, teacher| {
+            Event::test(id, MONDAY + starts_at)
+                .lasting(HOUR)
+                .kind(kind)
+                .subject(subject)
+                .groups([1])
+                .teachers([teacher])
+        };
+        let lab = EventKind::LaboratoryWork;
+        let timetable = Timetable::new(
+            [
+                // Two subgroups in parallel, one after the other in the second week
+                class(1, 0, lab.clone(), 1, 10),
+                class(2, 0, lab.clone(), 1, 11),
+                class(3, WEEK + HOUR, lab.clone(), 1, 11),
+                class(4, WEEK, lab.clone(), 1, 10),
+                // The whole group with one of the teachers
+                class(8, 2 * WEEK, lab.clone(), 1, 10),
+                // The whole group
+                class(5, 2 * HOUR, lab.clone(), 2, 12),
+                class(6, 4 * HOUR, EventKind::Lecture, 1, 13),
+                class(7, 4 * HOUR, EventKind::Lecture, 1, 14),
+            ],
+            [],
+        );
+
+        let subgroups = Subgroups::detect(&timetable, 1);
+        assert_eq!(subgroups.subgroups.len(), 2);
+        let first = &subgroups.subgroups[0];
+        assert_eq!((first.number, first.subject), (1, 1));
+        assert_eq!(first.teachers, BTreeSet::from([10]));
+        assert_eq!(first.events, [1, 4]);
+        assert_eq!(subgroups.subgroup_of(3).map(|s| s.number), Some(2));
+        assert_eq!(subgroups.subgroup_of(5), None);
+        assert_eq!(subgroups.subgroup_of(8), None);
+        assert_eq!(subgroups.of_subject(1).count(), 2);
+
+        let ids = |choice: &SubgroupChoice| {
+            let mut ids: Vec<i64> = subgroups
+                .filter(timetable.for_group(1), choice)
+                .map(|e| e.id)
+                .collect();
+            ids.sort_unstable();
+            ids
+        };
+        assert_eq!(ids(&SubgroupChoice::default()), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            ids(&SubgroupChoice::default().with(1, 2)),
+            [2, 3, 5, 6, 7, 8]
+        );
+        let other = SubgroupChoice {
+            default: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(ids(&other), [1, 4, 5, 6, 7, 8]);
+    }
+}
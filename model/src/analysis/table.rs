@@ -0,0 +1,57 @@
+use std::fmt::Write;
+
+/// Plain table of a report for UIs and exporters.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            header: header.into_iter().map(Into::into).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: impl IntoIterator<Item = impl ToString>) {
+        self.rows
+            .push(row.into_iter().map(|c| c.to_string()).collect());
+    }
+
+    /// CSV (RFC 4180) with the header.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                if i > 0 {
+                    csv.push(',');
+                }
+                if cell.contains([',', '"', '\n', '\r']) {
+                    let _ = write!(csv, "\"{}\"", cell.replace('"', "\"\""));
+                } else {
+                    csv.push_str(cell);
+                }
+            }
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv() {
+        let mut table = Table::new(["Група", "Пари"]);
+        table.push(["ПЗПІ-23-5", "4"]);
+        table.push(["\"Спец\", 1", "2"]);
+        assert_eq!(
+            table.to_csv(),
+            "Група,Пари\r\nПЗПІ-23-5,4\r\n\"\"\"Спец\"\", 1\",2\r\n"
+        );
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{Auditorium, Holidays, PairTables, Timetable, analysis::Table};
+
+use super::free_slots::overlaps;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// Occupied and available pairs by weekday (rows) and pair number (columns).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Heatmap {
+    pub weekdays: Vec<Weekday>,
+    pub pairs: Vec<u8>,
+    pub occupied: Vec<Vec<u32>>,
+    pub available: Vec<Vec<u32>>,
+}
+
+impl Heatmap {
+    fn new(weekdays: &[Weekday], pairs: &[u8]) -> Self {
+        let zeros = vec![vec![0; pairs.len()]; weekdays.len()];
+        Self {
+            weekdays: weekdays.to_vec(),
+            pairs: pairs.to_vec(),
+            occupied: zeros.clone(),
+            available: zeros,
+        }
+    }
+
+    fn add(&mut self, other: &Self) {
+        for (row, other) in self.occupied.iter_mut().zip(&other.occupied) {
+            row.iter_mut().zip(other).for_each(|(a, b)| *a += b);
+        }
+        for (row, other) in self.available.iter_mut().zip(&other.available) {
+            row.iter_mut().zip(other).for_each(|(a, b)| *a += b);
+        }
+    }
+
+    pub fn total_occupied(&self) -> u32 {
+        self.occupied.iter().flatten().sum()
+    }
+
+    pub fn total_available(&self) -> u32 {
+        self.available.iter().flatten().sum()
+    }
+
+    /// Occupied share of all the available pairs, from 0 to 1.
+    pub fn rate(&self) -> f64 {
+        ratio(self.total_occupied(), self.total_available())
+    }
+
+    /// Occupied share of every cell, from 0 to 1.
+    pub fn rates(&self) -> Vec<Vec<f64>> {
+        self.occupied
+            .iter()
+            .zip(&self.available)
+            .map(|(o, a)| o.iter().zip(a).map(|(o, a)| ratio(*o, *a)).collect())
+            .collect()
+    }
+
+    /// Rates in percent, a row per weekday and a column per pair.
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(
+            std::iter::once("weekday".to_owned()).chain(self.pairs.iter().map(u8::to_string)),
+        );
+        for (weekday, rates) in self.weekdays.iter().zip(self.rates()) {
+            table.push(
+                std::iter::once(weekday.to_string())
+                    .chain(rates.iter().map(|r| format!("{:.0}", r * 100.0))),
+            );
+        }
+        table
+    }
+}
+
+fn ratio(occupied: u32, available: u32) -> f64 {
+    if available == 0 {
+        return 0.0;
+    }
+    f64::from(occupied) / f64::from(available)
+}
+
+/// Heatmaps of every auditorium and building.
+#[derive(Clone, Debug, Default)]
+pub struct Utilization {
+    pub by_auditorium: BTreeMap<i64, Heatmap>,
+    pub by_building: BTreeMap<String, Heatmap>,
+}
+
+impl Utilization {
+    /// Auditoriums from the most to the least occupied.
+    pub fn ranking(&self) -> Vec<(i64, f64)> {
+        rank(&self.by_auditorium)
+    }
+
+    /// Buildings from the most to the least occupied.
+    pub fn building_ranking(&self) -> Vec<(String, f64)> {
+        rank(&self.by_building)
+    }
+
+    /// Up to `n` most occupied auditoriums.
+    pub fn top(&self, n: usize) -> Vec<(i64, f64)> {
+        self.ranking().into_iter().take(n).collect()
+    }
+
+    /// Up to `n` least occupied auditoriums, the emptiest first.
+    pub fn bottom(&self, n: usize) -> Vec<(i64, f64)> {
+        self.ranking().into_iter().rev().take(n).collect()
+    }
+}
+
+fn rank<K: Clone + Ord>(heatmaps: &BTreeMap<K, Heatmap>) -> Vec<(K, f64)> {
+    let mut ranking: Vec<_> = heatmaps
+        .iter()
+        .map(|(k, h)| (k.clone(), h.rate()))
+        .collect();
+    ranking.sort_by(|(a, x), (b, y)| y.total_cmp(x).then_with(|| a.cmp(b)));
+    ranking
+}
+
+/// Counts how many of the pairs the auditoriums are occupied.
+///
+/// ```rust
+/// use schedule_model::{Timetable, analysis::UtilizationReport};
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let from = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2025, 9, 30).unwrap();
+///
+/// let utilization = UtilizationReport::default().compute([], &Timetable::default(), from, to);
+/// assert!(utilization.ranking().is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct UtilizationReport {
+    pub pairs: PairTables,
+    pub weekdays: Vec<Weekday>,
+    /// Days off have no available pairs.
+    pub holidays: Holidays,
+}
+
+impl Default for UtilizationReport {
+    fn default() -> Self {
+        Self {
+            pairs: PairTables::default(),
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            holidays: Holidays::default(),
+        }
+    }
+}
+
+impl UtilizationReport {
+    /// Utilization of the `auditoriums` by the `timetable` between the local dates (inclusive).
+    ///
+    /// The online auditoriums are skipped, they are never full.
+    pub fn compute<'a>(
+        &self,
+        auditoriums: impl IntoIterator<Item = &'a Auditorium>,
+        timetable: &Timetable,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Utilization {
+        // (row, pair number, start, end)
+        let mut slots = Vec::new();
+        for date in from.iter_days().take_while(|d| *d <= to) {
+            let Some(row) = self.weekdays.iter().position(|w| *w == date.weekday()) else {
+                continue;
+            };
+            if self.holidays.is_day_off(date) {
+                continue;
+            }
+            for slot in self.pairs.for_date(date).slots() {
+                let (starts_at, ends_at) = slot.on(date);
+                slots.push((row, slot.number, starts_at, ends_at));
+            }
+        }
+        let pairs: Vec<u8> = slots
+            .iter()
+            .map(|(_, n, _, _)| *n)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let mut utilization = Utilization::default();
+        for auditorium in auditoriums.into_iter().filter(|a| !a.is_online()) {
+            let busy: Vec<_> = timetable
+                .for_auditorium(auditorium.id)
+                .map(|e| (e.starts_at, e.ends_at))
+                .collect();
+
+            let mut heatmap = Heatmap::new(&self.weekdays, &pairs);
+            for (row, number, starts_at, ends_at) in &slots {
+                let Ok(column) = pairs.binary_search(number) else {
+                    continue;
+                };
+                heatmap.available[*row][column] += 1;
+                if overlaps(&busy, *starts_at, *ends_at) {
+                    heatmap.occupied[*row][column] += 1;
+                }
+            }
+
+            utilization
+                .by_building
+                .entry(auditorium.building.clone())
+                .or_insert_with(|| Heatmap::new(&self.weekdays, &pairs))
+                .add(&heatmap);
+            utilization.by_auditorium.insert(auditorium.id, heatmap);
+        }
+        utilization
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Event;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    fn auditorium(id: i64, building: &str) -> Auditorium {
+        Auditorium {
+            id,
+            name: id.to_string(),
+            floor: 1,
+            power: false,
+            building: building.into(),
+        }
+    }
+
+    #[test]
+    fn utilization() {
+        let auditoriums = [
+            auditorium(1, "г"),
+            auditorium(2, "г"),
+            auditorium(3, "и"),
+            Auditorium::online(-4).unwrap(),
+        ];
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, day(1), 1),
+                Event::test_pair(2, day(8), 1),
+                Event::test_pair(3, day(1), 2),
+                Event::test_pair(4, day(2), 1).auditorium(3),
+                Event::test_pair(5, day(2), 1).auditorium(-4),
+            ],
+            [],
+        );
+
+        // Two weeks, 10 working days by 8 pairs
+        let report = UtilizationReport::default();
+        let utilization = report.compute(&auditoriums, &timetable, day(1), day(14));
+
+        assert!(!utilization.by_auditorium.contains_key(&-4));
+        assert!(!utilization.by_building.contains_key("dl"));
+
+        let first = &utilization.by_auditorium[&1];
+        assert_eq!(first.pairs, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(first.total_available(), 80);
+        assert_eq!(first.occupied[0][..3], [2, 1, 0]);
+        assert_eq!(first.rates()[0][0], 1.0);
+        assert_eq!(first.rates()[0][1], 0.5);
+
+        let building = &utilization.by_building["г"];
+        assert_eq!(
+            (building.total_occupied(), building.total_available()),
+            (3, 160)
+        );
+
+        assert_eq!(
+            utilization
+                .ranking()
+                .iter()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>(),
+            [1, 3, 2]
+        );
+        assert_eq!(utilization.bottom(1)[0], (2, 0.0));
+        assert_eq!(utilization.building_ranking()[0].0, "г");
+
+        let table = first.table();
+        assert_eq!(table.rows.len(), 5);
+        assert_eq!(table.rows[0][..3], ["Mon", "100", "50"]);
+
+        // Independence Day is a day off, but not during martial law
+        let august = |d| NaiveDate::from_ymd_opt(2026, 8, d).unwrap();
+        let monday = report.compute(&auditoriums, &timetable, august(24), august(24));
+        assert_eq!(monday.by_auditorium[&1].total_available(), 0);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    EventKind, Language, Timetable, TimetableKind,
+    analysis::{Table, academic_hours},
+    time::local_timestamp,
+};
+
+use std::collections::BTreeMap;
+
+use chrono::{NaiveDate, NaiveTime, Weekday};
+
+/// Contact hours (academic) of a teacher over a date range.
+///
+/// ```rust
+/// use schedule_model::{Timetable, analysis::Workload};
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let september = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+/// let december = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+///
+/// let workload = Workload::of(&Timetable::default(), 1, september, december);
+/// assert_eq!(workload.hours, 0);
+/// println!("{}", Workload::table([&workload]).to_csv());
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Workload {
+    pub teacher: i64,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub classes: u32,
+    pub hours: u32,
+    /// By Monday of the week.
+    pub by_week: BTreeMap<NaiveDate, u32>,
+    pub by_date: BTreeMap<NaiveDate, u32>,
+    pub by_kind: BTreeMap<EventKind, u32>,
+    pub by_group: BTreeMap<i64, u32>,
+    pub by_subject: BTreeMap<i64, u32>,
+}
+
+impl Workload {
+    /// Workload of the `teacher` by their classes in the `timetable` between the local dates (inclusive).
+    pub fn of(timetable: &Timetable, teacher: i64, from: NaiveDate, to: NaiveDate) -> Self {
+        let mut workload = Self {
+            teacher,
+            from,
+            to,
+            classes: 0,
+            hours: 0,
+            by_week: BTreeMap::new(),
+            by_date: BTreeMap::new(),
+            by_kind: BTreeMap::new(),
+            by_group: BTreeMap::new(),
+            by_subject: BTreeMap::new(),
+        };
+
+        let starts = local_timestamp(from, NaiveTime::MIN);
+        let ends = to
+            .succ_opt()
+            .map_or(i64::MAX, |next| local_timestamp(next, NaiveTime::MIN));
+
+        for event in timetable
+            .events_of(TimetableKind::Teacher(teacher))
+            .filter(|e| starts <= e.starts_at && e.starts_at < ends)
+        {
+            let hours = academic_hours(event.ends_at - event.starts_at);
+            let date = event.date();
+
+            workload.classes += 1;
+            workload.hours += hours;
+            *workload
+                .by_week
+                .entry(date.week(Weekday::Mon).first_day())
+                .or_default() += hours;
+            *workload.by_date.entry(date).or_default() += hours;
+            *workload.by_kind.entry(event.kind.clone()).or_default() += hours;
+            *workload.by_subject.entry(event.subject).or_default() += hours;
+            for group in &event.groups {
+                *workload.by_group.entry(*group).or_default() += hours;
+            }
+        }
+
+        workload
+    }
+
+    /// Average hours per week of the date range.
+    pub fn weekly_average(&self) -> f64 {
+        let days = (self.to - self.from).num_days() + 1;
+        if days <= 0 {
+            return 0.0;
+        }
+        f64::from(self.hours) * 7.0 / days as f64
+    }
+
+    /// Up to `n` busiest days, the earlier ones go first among equal.
+    pub fn peak_days(&self, n: usize) -> Vec<(NaiveDate, u32)> {
+        let mut days: Vec<_> = self.by_date.iter().map(|(d, h)| (*d, *h)).collect();
+        days.sort_by_key(|(date, hours)| (std::cmp::Reverse(*hours), *date));
+        days.truncate(n);
+        days
+    }
+
+    /// Summary of the `workloads`, a row per teacher with hours by each kind of classes.
+    pub fn table<'a>(workloads: impl IntoIterator<Item = &'a Self>) -> Table {
+        let workloads = Vec::from_iter(workloads);
+        let mut kinds: Vec<&EventKind> = workloads.iter().flat_map(|w| w.by_kind.keys()).collect();
+        kinds.sort();
+        kinds.dedup();
+
+        let mut table = Table::new(
+            ["teacher", "classes", "hours", "weekly_average", "peak_day"]
+                .into_iter()
+                .map(String::from)
+                .chain(
+                    kinds
+                        .iter()
+                        .map(|k| k.short_name(Language::English).to_owned()),
+                ),
+        );
+        for w in workloads {
+            let peak = w.peak_days(1).first().map(|(d, _)| d.to_string());
+            let row = [
+                w.teacher.to_string(),
+                w.classes.to_string(),
+                w.hours.to_string(),
+                format!("{:.1}", w.weekly_average()),
+                peak.unwrap_or_default(),
+            ];
+            let by_kind = kinds
+                .iter()
+                .map(|k| w.by_kind.get(*k).copied().unwrap_or_default().to_string());
+            table.push(row.into_iter().chain(by_kind));
+        }
+        table
+    }
+
+    /// Hours of the teacher by kind, group, subject and week, a row per value.
+    pub fn breakdown(&self) -> Table {
+        let mut table = Table::new(["by", "key", "hours"]);
+        for (kind, hours) in &self.by_kind {
+            table.push(["kind".to_owned(), kind.id().into_owned(), hours.to_string()]);
+        }
+        for (group, hours) in &self.by_group {
+            table.push(["group".to_owned(), group.to_string(), hours.to_string()]);
+        }
+        for (subject, hours) in &self.by_subject {
+            table.push(["subject".to_owned(), subject.to_string(), hours.to_string()]);
+        }
+        for (week, hours) in &self.by_week {
+            table.push(["week".to_owned(), week.to_string(), hours.to_string()]);
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Event;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    #[test]
+    fn workload() {
+        let other_teacher = Event::test_pair(6, day(1), 3).groups([1]).teachers([8]);
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, day(1), 1).groups([1, 2]).teachers([7]),
+                Event::test_pair(2, day(1), 2)
+                    .subject(2)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([1])
+                    .teachers([7]),
+                Event::test_pair(3, day(2), 1)
+                    .subject(2)
+                    .kind(EventKind::LaboratoryWork)
+                    .groups([2])
+                    .teachers([7]),
+                Event::test_pair(4, day(8), 1).groups([1, 2]).teachers([7]),
+                Event::test_pair(5, day(15), 1).groups([1, 2]).teachers([7]), // out of range
+                other_teacher,
+            ],
+            [],
+        );
+
+        let workload = Workload::of(&timetable, 7, day(1), day(14));
+        assert_eq!((workload.classes, workload.hours), (4, 8));
+        assert_eq!(workload.weekly_average(), 4.0);
+        assert_eq!(workload.by_week, BTreeMap::from([(day(1), 6), (day(8), 2)]));
+        assert_eq!(workload.by_kind[&EventKind::LaboratoryWork], 4);
+        assert_eq!(workload.by_group, BTreeMap::from([(1, 6), (2, 6)]));
+        assert_eq!(workload.by_subject, BTreeMap::from([(1, 4), (2, 4)]));
+        assert_eq!(workload.peak_days(2), [(day(1), 4), (day(2), 2)]);
+
+        let table = Workload::table([&workload]);
+        assert_eq!(
+            table.header,
+            [
+                "teacher",
+                "classes",
+                "hours",
+                "weekly_average",
+                "peak_day",
+                "Lec",
+                "Lab"
+            ]
+        );
+        assert_eq!(
+            table.rows[0],
+            ["7", "4", "8", "4.0", "2025-09-01", "4", "4"]
+        );
+        assert_eq!(workload.breakdown().rows.len(), 2 + 2 + 2 + 2);
+    }
+}
@@ -1,5 +1,37 @@
+mod name;
+
+pub use name::AuditoriumName;
+
+use name::ONLINE_BUILDING;
+
 use proc::PartialBorrow;
 
+/// Where the classes take place.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Location {
+    /// A room of a building.
+    Physical,
+    /// Distance learning, any number of classes may be held at once.
+    Online,
+    /// Neither, e.g. a gym or an id without a stored auditorium.
+    Unknown,
+}
+
+/// Known distance learning "auditoriums" of the upstream by id.
+pub const ONLINE_AUDITORIUMS: [(i64, &str); 1] = [(-4, "DL_1")];
+
+impl Location {
+    /// By the auditorium id alone: online if it's one of [`ONLINE_AUDITORIUMS`],
+    /// the rest (the other negative ids included) are unknown.
+    pub fn of_id(id: i64) -> Self {
+        if ONLINE_AUDITORIUMS.iter().any(|(online, _)| *online == id) {
+            Self::Online
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
 #[derive(Clone, Ord, PartialOrd, Debug, PartialBorrow)]
 pub struct Auditorium {
     #[borrow_id]
@@ -9,3 +41,88 @@ pub struct Auditorium {
     pub power: bool,
     pub building: String,
 }
+
+impl Auditorium {
+    /// Derives the missing `floor` and `building` from the name,
+    /// the ones that can't be derived are 0 and empty. The building is lowercase.
+    pub fn with_name(
+        id: i64,
+        name: String,
+        floor: Option<i8>,
+        power: bool,
+        building: Option<String>,
+    ) -> Self {
+        let parsed = AuditoriumName::parse(&name);
+        Self {
+            id,
+            floor: floor.or(parsed.floor).unwrap_or_default(),
+            building: building
+                .map(|b| b.trim().to_lowercase())
+                .filter(|b| !b.is_empty())
+                .or(parsed.building)
+                .unwrap_or_default(),
+            name,
+            power,
+        }
+    }
+
+    /// A known distance learning auditorium, see [`ONLINE_AUDITORIUMS`].
+    pub fn online(id: i64) -> Option<Self> {
+        let (_, name) = ONLINE_AUDITORIUMS
+            .iter()
+            .find(|(online, _)| *online == id)?;
+        Some(Self::with_name(id, (*name).to_owned(), None, false, None))
+    }
+
+    pub fn parsed_name(&self) -> AuditoriumName {
+        AuditoriumName::parse(&self.name)
+    }
+
+    /// Online by the id, name or building, physical if the building is known.
+    pub fn location(&self) -> Location {
+        let parsed = self.parsed_name();
+        if Location::of_id(self.id) == Location::Online
+            || parsed.online
+            || self.building == ONLINE_BUILDING
+        {
+            Location::Online
+        } else if self.building.trim().is_empty() && parsed.is_unknown() {
+            Location::Unknown
+        } else {
+            Location::Physical
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.location() == Location::Online
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let online = Auditorium::online(-4).unwrap();
+        assert_eq!(
+            (online.name.as_str(), online.building.as_str()),
+            ("DL_1", "dl")
+        );
+        assert!(online.is_online());
+        assert_eq!(Auditorium::online(93), None);
+
+        let room = Auditorium::with_name(93, "287".into(), None, true, None);
+        assert_eq!(room.location(), Location::Physical);
+        let hall = Auditorium::with_name(94, "Спортзал".into(), None, false, None);
+        assert_eq!(hall.location(), Location::Unknown);
+        let zoom = Auditorium::with_name(95, "Онлайн".into(), None, false, None);
+        assert_eq!(zoom.location(), Location::Online);
+        let upper = Auditorium::with_name(96, "З-201".into(), None, false, Some("З".into()));
+        assert_eq!(upper.building, "з");
+
+        assert_eq!(Location::of_id(-4), Location::Online);
+        assert_eq!(Location::of_id(-7), Location::Unknown);
+        assert_eq!(Location::of_id(93), Location::Unknown);
+    }
+}
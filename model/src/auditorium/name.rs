@@ -0,0 +1,124 @@
+/// What the name of a NURE auditorium tells about it, e.g. "287", "и-305", "ФІЛІЯ" or "DL_1".
+///
+/// ```rust
+/// use schedule_model::AuditoriumName;
+///
+/// let name = AuditoriumName::parse("и-305");
+/// assert_eq!(name.building.as_deref(), Some("и"));
+/// assert_eq!(name.floor, Some(3));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AuditoriumName {
+    /// Upstream building id, "г" for the main one.
+    pub building: Option<String>,
+    pub floor: Option<i8>,
+    /// Distance learning, not a room.
+    pub online: bool,
+}
+
+/// Building of the rooms named only by a number.
+const MAIN_BUILDING: &str = "г";
+/// Building id of the distance learning "rooms".
+pub(super) const ONLINE_BUILDING: &str = "dl";
+
+impl AuditoriumName {
+    pub fn parse(name: &str) -> Self {
+        let name = name.trim();
+        let lower = name.to_lowercase();
+
+        if lower.starts_with("dl")
+            || ["онлайн", "online", "дист"]
+                .iter()
+                .any(|w| lower.contains(w))
+        {
+            return Self {
+                building: Some(ONLINE_BUILDING.to_owned()),
+                floor: Some(0),
+                online: true,
+            };
+        }
+        if lower.starts_with("філія") {
+            return Self {
+                building: Some("ф".to_owned()),
+                ..Default::default()
+            };
+        }
+
+        // "287", "287а", "и-305", "и305"
+        let letters = lower
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(lower.len());
+        let (building, rest) = lower.split_at(letters);
+        let rest = rest.strip_prefix(['-', ' ', '_']).unwrap_or(rest);
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if !(3..=4).contains(&digits) {
+            return Self::default();
+        }
+
+        Self {
+            building: Some(if building.is_empty() {
+                MAIN_BUILDING.to_owned()
+            } else {
+                building.to_owned()
+            }),
+            floor: rest[..digits - 2].parse().ok(),
+            online: false,
+        }
+    }
+
+    /// Whether nothing is known besides the name.
+    pub fn is_unknown(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the known parts are the same as the upstream `floor` and `building`.
+    pub fn agrees_with(&self, floor: i8, building: &str) -> bool {
+        self.floor.is_none_or(|f| f == floor)
+            && self
+                .building
+                .as_deref()
+                .is_none_or(|b| b.to_lowercase() == building.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        for (name, building, floor, online) in [
+            ("287", Some("г"), Some(2), false),
+            ("035", Some("г"), Some(0), false),
+            ("161а", Some("г"), Some(1), false),
+            ("и-305", Some("и"), Some(3), false),
+            ("З 201", Some("з"), Some(2), false),
+            ("ФІЛІЯ", Some("ф"), None, false),
+            ("DL_1", Some("dl"), Some(0), true),
+            ("Онлайн", Some("dl"), Some(0), true),
+            ("Спортзал", None, None, false),
+            ("12", None, None, false),
+        ] {
+            let parsed = AuditoriumName::parse(name);
+            assert_eq!(parsed.building.as_deref(), building, "{name}");
+            assert_eq!(parsed.floor, floor, "{name}");
+            assert_eq!(parsed.online, online, "{name}");
+        }
+        assert!(AuditoriumName::parse("Спортзал").is_unknown());
+    }
+
+    #[test]
+    fn agrees() {
+        assert!(AuditoriumName::parse("287").agrees_with(2, "г"));
+        assert!(!AuditoriumName::parse("287").agrees_with(3, "г"));
+        assert!(AuditoriumName::parse("ФІЛІЯ").agrees_with(1, "ф"));
+        assert!(AuditoriumName::parse("И-305").agrees_with(3, "и"));
+        assert!(AuditoriumName::parse("и-305").agrees_with(3, "И"));
+        assert!(AuditoriumName::parse("287").agrees_with(2, "Г"));
+        assert!(!AuditoriumName::parse("и-305").agrees_with(3, "г"));
+        assert!(AuditoriumName::parse("DL_1").agrees_with(0, "DL"));
+        assert!(AuditoriumName::parse("Спортзал").agrees_with(1, "с"));
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{Event, EventKind, Timetable, time::local_timestamp};
+
+use std::ops::RangeInclusive;
+
+use chrono::{Datelike, Month, NaiveDate, NaiveTime, TimeDelta};
+
+/// How far apart (in days) classes have to be to belong to different semesters.
+const SEMESTER_GAP: i64 = 21;
+
+/// Numerator (odd) and denominator (even) teaching weeks.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum WeekParity {
+    Odd,
+    Even,
+}
+
+impl WeekParity {
+    pub fn of(week: u32) -> Self {
+        if week % 2 == 1 { Self::Odd } else { Self::Even }
+    }
+}
+
+/// Teaching period followed by an optional exam session, dates are local and inclusive.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Semester {
+    pub teaching: RangeInclusive<NaiveDate>,
+    pub session: Option<RangeInclusive<NaiveDate>>,
+}
+
+impl Semester {
+    pub fn new(teaching: RangeInclusive<NaiveDate>) -> Self {
+        Self {
+            teaching,
+            session: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_session(mut self, session: RangeInclusive<NaiveDate>) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// The usual autumn semester: from September to December, exams in January.
+    pub fn autumn(year: i32) -> Option<Self> {
+        Some(
+            Self::new(date(year, Month::September, 1)?..=date(year, Month::December, 31)?)
+                .with_session(
+                    date(year + 1, Month::January, 1)?..=date(year + 1, Month::January, 31)?,
+                ),
+        )
+    }
+
+    /// The usual spring semester: from the first Monday of February to May, exams in June.
+    pub fn spring(year: i32) -> Option<Self> {
+        let february = date(year, Month::February, 1)?;
+        let offset = (7 - february.weekday().num_days_from_monday()) % 7;
+        let starts = february + TimeDelta::days(offset.into());
+        Some(
+            Self::new(starts..=date(year, Month::May, 31)?)
+                .with_session(date(year, Month::June, 1)?..=date(year, Month::June, 30)?),
+        )
+    }
+
+    pub fn starts(&self) -> NaiveDate {
+        *self.teaching.start()
+    }
+
+    /// Last day of the session, or of the teaching if there is none.
+    pub fn ends(&self) -> NaiveDate {
+        self.session
+            .as_ref()
+            .map_or(*self.teaching.end(), |s| *s.end())
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.starts() <= date && date <= self.ends()
+    }
+
+    pub fn is_session(&self, date: NaiveDate) -> bool {
+        self.session.as_ref().is_some_and(|s| s.contains(&date))
+    }
+
+    /// Number of the teaching week, the first one is the week (from Monday) of the start.
+    pub fn week(&self, date: NaiveDate) -> Option<u32> {
+        if !self.teaching.contains(&date) || self.is_session(date) {
+            return None;
+        }
+        let weeks = (monday(date) - monday(self.starts())).num_weeks();
+        u32::try_from(weeks + 1).ok()
+    }
+
+    pub fn parity(&self, date: NaiveDate) -> Option<WeekParity> {
+        self.week(date).map(WeekParity::of)
+    }
+
+    /// Teaching days of the week `number`.
+    pub fn week_dates(&self, number: u32) -> Option<RangeInclusive<NaiveDate>> {
+        let starts = monday(self.starts()) + TimeDelta::weeks(i64::from(number.checked_sub(1)?));
+        let ends = starts + TimeDelta::days(6);
+        let starts = starts.max(self.starts());
+        let ends = ends.min(*self.teaching.end());
+        (number > 0 && starts <= ends).then_some(starts..=ends)
+    }
+
+    /// Number of teaching weeks.
+    pub fn weeks(&self) -> u32 {
+        self.week(*self.teaching.end()).unwrap_or_default()
+    }
+}
+
+/// Semesters of one or several academic years.
+///
+/// ```rust
+/// use schedule_model::{AcademicCalendar, WeekParity};
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let calendar = AcademicCalendar::for_year(2025);
+/// let date = NaiveDate::from_ymd_opt(2025, 10, 15).unwrap();
+///
+/// assert_eq!(calendar.week(date), Some(7));
+/// assert_eq!(calendar.parity(date), Some(WeekParity::Odd));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct AcademicCalendar {
+    semesters: Vec<Semester>, // sorted by start
+}
+
+impl AcademicCalendar {
+    pub fn new(semesters: impl IntoIterator<Item = Semester>) -> Self {
+        let mut semesters = Vec::from_iter(semesters);
+        semesters.sort_by_key(|s| s.starts());
+        Self { semesters }
+    }
+
+    /// The usual semesters of the academic year starting in September of `year`.
+    pub fn for_year(year: i32) -> Self {
+        Self::new(
+            [Semester::autumn(year), Semester::spring(year + 1)]
+                .into_iter()
+                .flatten(),
+        )
+    }
+
+    /// Semesters as the `timetable` has them: classes more than three weeks apart
+    /// belong to different semesters. The session starts with the first exam,
+    /// or earlier if only final tests, consultations and exams are left after the last class.
+    ///
+    /// The teaching period is empty if the session starts on the first day.
+    pub fn derive(timetable: &Timetable) -> Self {
+        let mut clusters: Vec<Vec<&Event>> = Vec::new();
+        for event in timetable.iter() {
+            match clusters.last_mut() {
+                Some(cluster)
+                    if (event.date() - cluster[cluster.len() - 1].date()).num_days()
+                        < SEMESTER_GAP =>
+                {
+                    cluster.push(event);
+                }
+                _ => clusters.push(vec![event]),
+            }
+        }
+
+        Self::new(clusters.into_iter().map(|events| {
+            let starts = events[0].date();
+            let ends = events[events.len() - 1].date();
+
+            let first_exam = events
+                .iter()
+                .find(|e| e.kind == EventKind::Exam)
+                .map(|e| e.date());
+            let last_class = events
+                .iter()
+                .rev()
+                .find(|e| {
+                    !matches!(
+                        e.kind,
+                        EventKind::Exam | EventKind::FinalTest | EventKind::Consultation
+                    )
+                })
+                .map(|e| e.date());
+            let final_tests = events
+                .iter()
+                .filter(|e| last_class.is_none_or(|last| e.date() > last))
+                .find(|e| matches!(e.kind, EventKind::Exam | EventKind::FinalTest))
+                .map(|e| e.date());
+
+            match first_exam.into_iter().chain(final_tests).min() {
+                Some(session_starts) => {
+                    let teaching_ends = session_starts.pred_opt().unwrap_or(session_starts);
+                    Semester::new(starts..=teaching_ends).with_session(session_starts..=ends)
+                }
+                None => Semester::new(starts..=ends),
+            }
+        }))
+    }
+
+    #[must_use]
+    pub fn with(self, semester: Semester) -> Self {
+        Self::new(self.semesters.into_iter().chain([semester]))
+    }
+
+    pub fn semesters(&self) -> &[Semester] {
+        &self.semesters
+    }
+
+    pub fn semester(&self, date: NaiveDate) -> Option<&Semester> {
+        self.semesters.iter().rev().find(|s| s.contains(date))
+    }
+
+    pub fn week(&self, date: NaiveDate) -> Option<u32> {
+        self.semester(date)?.week(date)
+    }
+
+    pub fn parity(&self, date: NaiveDate) -> Option<WeekParity> {
+        self.semester(date)?.parity(date)
+    }
+
+    pub fn is_session(&self, date: NaiveDate) -> bool {
+        self.semester(date).is_some_and(|s| s.is_session(date))
+    }
+}
+
+impl Timetable {
+    /// Events between the local dates (inclusive), ordered by the start.
+    pub fn between(
+        &self,
+        dates: RangeInclusive<NaiveDate>,
+    ) -> impl DoubleEndedIterator<Item = &Event> {
+        let from = local_timestamp(*dates.start(), NaiveTime::MIN);
+        let to = dates
+            .end()
+            .succ_opt()
+            .map_or(i64::MAX, |next| local_timestamp(next, NaiveTime::MIN));
+        self.range(from..to)
+    }
+
+    /// Events of the teaching week `number` of the `semester`.
+    pub fn teaching_week<'a>(
+        &'a self,
+        semester: &Semester,
+        number: u32,
+    ) -> impl DoubleEndedIterator<Item = &'a Event> {
+        let dates = semester.week_dates(number);
+        dates.into_iter().flat_map(|dates| self.between(dates))
+    }
+
+    /// Events of the odd or even teaching weeks.
+    pub fn with_parity<'a>(
+        &'a self,
+        calendar: &'a AcademicCalendar,
+        parity: WeekParity,
+    ) -> impl DoubleEndedIterator<Item = &'a Event> {
+        self.iter()
+            .filter(move |e| calendar.parity(e.date()) == Some(parity))
+    }
+
+    /// Events during the exam session of the `semester`.
+    pub fn session<'a>(
+        &'a self,
+        semester: &Semester,
+    ) -> impl DoubleEndedIterator<Item = &'a Event> {
+        let dates = semester.session.clone();
+        dates.into_iter().flat_map(|dates| self.between(dates))
+    }
+}
+
+fn date(year: i32, month: Month, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month.number_from_month(), day)
+}
+
+fn monday(date: NaiveDate) -> NaiveDate {
+    date - TimeDelta::days(date.weekday().num_days_from_monday().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn weeks() {
+        let autumn = Semester::autumn(2025).unwrap();
+
+        assert_eq!(autumn.week(day(9, 1)), Some(1)); // Monday
+        assert_eq!(autumn.week(day(9, 7)), Some(1));
+        assert_eq!(autumn.week(day(9, 8)), Some(2));
+        assert_eq!(autumn.parity(day(9, 8)), Some(WeekParity::Even));
+        assert_eq!(autumn.week(day(8, 31)), None);
+        assert_eq!(autumn.weeks(), 18);
+        assert_eq!(autumn.week_dates(2), Some(day(9, 8)..=day(9, 14)));
+        assert_eq!(autumn.week_dates(0), None);
+        assert_eq!(autumn.week_dates(19), None);
+
+        let calendar = AcademicCalendar::for_year(2025);
+        let exams = NaiveDate::from_ymd_opt(2026, 1, 12).unwrap();
+        assert!(calendar.is_session(exams));
+        assert_eq!(calendar.week(exams), None);
+        let spring = NaiveDate::from_ymd_opt(2026, 2, 2).unwrap(); // Monday
+        assert_eq!(calendar.week(spring), Some(1));
+    }
+
+    #[test]
+    fn derive() {
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, day(2, 3), 1), // Tuesday
+                Event::test_pair(2, day(2, 10), 1),
+                Event::test_pair(3, day(2, 17), 1).kind(EventKind::Consultation),
+                Event::test_pair(4, day(2, 20), 1).kind(EventKind::Exam),
+                Event::test_pair(5, day(9, 1), 1),
+            ],
+            [],
+        );
+        let calendar = AcademicCalendar::derive(&timetable);
+
+        let spring = &calendar.semesters()[0];
+        assert_eq!(spring.teaching, day(2, 3)..=day(2, 19));
+        assert_eq!(spring.session, Some(day(2, 20)..=day(2, 20)));
+        assert_eq!(calendar.semesters()[1].teaching, day(9, 1)..=day(9, 1));
+
+        assert_eq!(calendar.week(day(2, 10)), Some(2));
+        let ids = |events: Vec<&Event>| events.iter().map(|e| e.id).collect::<Vec<_>>();
+        assert_eq!(ids(timetable.teaching_week(spring, 2).collect()), [2]);
+        assert_eq!(ids(timetable.session(spring).collect()), [4]);
+        assert_eq!(
+            ids(timetable.with_parity(&calendar, WeekParity::Odd).collect()),
+            [1, 3, 5]
+        );
+    }
+
+    #[test]
+    fn derive_final_tests() {
+        // A залік in the middle of the semester, the session starts with the final tests
+        let lectures =
+            (0..17).map(|week| Event::test_pair(10 + week, day(9, 1) + TimeDelta::weeks(week), 1));
+        let timetable = Timetable::new(
+            lectures.chain([
+                Event::test_pair(2, day(10, 6), 1).kind(EventKind::FinalTest),
+                Event::test_pair(4, day(12, 25), 1).kind(EventKind::FinalTest),
+                Event::test_pair(5, day(12, 26), 1).kind(EventKind::Consultation),
+                Event::test_pair(6, day(12, 29), 1).kind(EventKind::Exam),
+            ]),
+            [],
+        );
+        let calendar = AcademicCalendar::derive(&timetable);
+        let autumn = &calendar.semesters()[0];
+        assert_eq!(autumn.teaching, day(9, 1)..=day(12, 24));
+        assert_eq!(autumn.session, Some(day(12, 25)..=day(12, 29)));
+        assert_eq!(autumn.week(day(10, 6)), Some(6));
+
+        // A class on the same day as the first exam
+        let timetable = Timetable::new(
+            [
+                Event::test_pair(1, day(9, 1), 1),
+                Event::test_pair(2, day(9, 15), 1).kind(EventKind::Exam),
+                Event::test_pair(3, day(9, 15), 1).kind(EventKind::LaboratoryWork),
+                Event::test_pair(4, day(9, 16), 1).kind(EventKind::Exam),
+            ],
+            [],
+        );
+        let calendar = AcademicCalendar::derive(&timetable);
+        let semester = &calendar.semesters()[0];
+        assert_eq!(semester.teaching, day(9, 1)..=day(9, 14));
+        assert_eq!(semester.session, Some(day(9, 15)..=day(9, 16)));
+        assert!(calendar.is_session(day(9, 15)));
+
+        // Only final tests
+        let timetable = Timetable::new(
+            [Event::test_pair(1, day(6, 2), 1).kind(EventKind::FinalTest)],
+            [],
+        );
+        let calendar = AcademicCalendar::derive(&timetable);
+        let session = &calendar.semesters()[0];
+        assert_eq!(session.session, Some(day(6, 2)..=day(6, 2)));
+        assert!(session.teaching.is_empty());
+    }
+}
@@ -1,5 +1,9 @@
+mod kind;
+
+pub use kind::{EventKind, ParseEventKindError};
+
 use proc::PartialBorrow;
-use std::{collections::HashSet, hash::Hash};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug, PartialBorrow)]
 pub struct Event {
@@ -27,16 +31,68 @@ impl PartialOrd for Event {
     }
 }
 
-// #[non_exhaustive] // just in case
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub enum EventKind {
-    Lecture,
-    PracticalWork,
-    LaboratoryWork,
-    Consultation,
-    /// Залік
-    FinalTest,
-    Exam,
-    CourseWork,
-    Unknown,
+/// Builder for the tests: a 95 minutes lecture of the subject 1 in the auditorium 1
+/// without groups and teachers.
+#[cfg(any(test, feature = "test-util"))]
+impl Event {
+    pub fn test(id: i64, starts_at: i64) -> Self {
+        Self {
+            id,
+            starts_at,
+            ends_at: starts_at + 95 * 60,
+            kind: EventKind::Lecture,
+            count: 1,
+            subject: 1,
+            auditorium: 1,
+            groups: HashSet::new(),
+            teachers: HashSet::new(),
+        }
+    }
+
+    /// At the time of the NURE `pair` on the local `date`, counted as the `pair`.
+    #[cfg(feature = "chrono")]
+    pub fn test_pair(id: i64, date: chrono::NaiveDate, pair: u8) -> Self {
+        let (starts_at, ends_at) = crate::PairSchedule::nure().get(pair).unwrap().on(date);
+        Self {
+            ends_at,
+            ..Self::test(id, starts_at).count(pair)
+        }
+    }
+
+    /// Lasting `seconds` from the start.
+    #[must_use]
+    pub fn lasting(mut self, seconds: i64) -> Self {
+        self.ends_at = self.starts_at + seconds;
+        self
+    }
+    #[must_use]
+    pub fn kind(mut self, kind: EventKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    #[must_use]
+    pub fn count(mut self, count: u8) -> Self {
+        self.count = count;
+        self
+    }
+    #[must_use]
+    pub fn subject(mut self, subject: i64) -> Self {
+        self.subject = subject;
+        self
+    }
+    #[must_use]
+    pub fn auditorium(mut self, auditorium: i64) -> Self {
+        self.auditorium = auditorium;
+        self
+    }
+    #[must_use]
+    pub fn groups(mut self, groups: impl IntoIterator<Item = i64>) -> Self {
+        self.groups = HashSet::from_iter(groups);
+        self
+    }
+    #[must_use]
+    pub fn teachers(mut self, teachers: impl IntoIterator<Item = i64>) -> Self {
+        self.teachers = HashSet::from_iter(teachers);
+        self
+    }
 }
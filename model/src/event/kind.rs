@@ -0,0 +1,230 @@
+use crate::Language;
+
+use std::{borrow::Cow, str::FromStr};
+
+/// Of the identifiers of [`EventKind::Unknown`].
+const UNKNOWN_PREFIX: &str = "unknown:";
+
+// #[non_exhaustive] // just in case
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum EventKind {
+    Lecture,
+    PracticalWork,
+    LaboratoryWork,
+    Consultation,
+    /// Залік
+    FinalTest,
+    Exam,
+    CourseWork,
+    /// Not recognized, keeps the raw label from the upstream so it can be mapped later.
+    Unknown(String),
+}
+
+impl EventKind {
+    /// All the known kinds.
+    pub const KNOWN: [Self; 7] = [
+        Self::Lecture,
+        Self::PracticalWork,
+        Self::LaboratoryWork,
+        Self::Consultation,
+        Self::FinalTest,
+        Self::Exam,
+        Self::CourseWork,
+    ];
+
+    /// The exact upstream label, anything else becomes [`EventKind::Unknown`].
+    /// Unlike [`FromStr`], no aliases, so a new upstream label is never taken for a known kind.
+    pub fn from_label(label: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|kind| kind.short_name(Language::Ukrainian) == label)
+            .unwrap_or_else(|| Self::Unknown(label.to_owned()))
+    }
+
+    /// Stable identifier for configs, URLs and such.
+    /// The raw label with the `unknown:` prefix for unknown kinds, so they never collide.
+    pub fn id(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self {
+            Self::Lecture => "lecture",
+            Self::PracticalWork => "practical",
+            Self::LaboratoryWork => "laboratory",
+            Self::Consultation => "consultation",
+            Self::FinalTest => "final_test",
+            Self::Exam => "exam",
+            Self::CourseWork => "course_work",
+            Self::Unknown(label) => return Cow::Owned(format!("{UNKNOWN_PREFIX}{label}")),
+        })
+    }
+
+    pub fn name(&self, language: Language) -> &str {
+        match (self, language) {
+            (Self::Lecture, Language::Ukrainian) => "Лекція",
+            (Self::Lecture, Language::English) => "Lecture",
+            (Self::PracticalWork, Language::Ukrainian) => "Практичне заняття",
+            (Self::PracticalWork, Language::English) => "Practical work",
+            (Self::LaboratoryWork, Language::Ukrainian) => "Лабораторна робота",
+            (Self::LaboratoryWork, Language::English) => "Laboratory work",
+            (Self::Consultation, Language::Ukrainian) => "Консультація",
+            (Self::Consultation, Language::English) => "Consultation",
+            (Self::FinalTest, Language::Ukrainian) => "Залік",
+            (Self::FinalTest, Language::English) => "Final test",
+            (Self::Exam, Language::Ukrainian) => "Екзамен",
+            (Self::Exam, Language::English) => "Exam",
+            (Self::CourseWork, Language::Ukrainian) => "Курсовий проєкт / робота",
+            (Self::CourseWork, Language::English) => "Course project / work",
+            (Self::Unknown(label), _) => label,
+        }
+    }
+
+    /// Abbreviation, the Ukrainian ones are the upstream labels.
+    pub fn short_name(&self, language: Language) -> &str {
+        match (self, language) {
+            (Self::Lecture, Language::Ukrainian) => "Лк",
+            (Self::Lecture, Language::English) => "Lec",
+            (Self::PracticalWork, Language::Ukrainian) => "Пз",
+            (Self::PracticalWork, Language::English) => "Pr",
+            (Self::LaboratoryWork, Language::Ukrainian) => "Лб",
+            (Self::LaboratoryWork, Language::English) => "Lab",
+            (Self::Consultation, Language::Ukrainian) => "Конс",
+            (Self::Consultation, Language::English) => "Cons",
+            (Self::FinalTest, Language::Ukrainian) => "Зал",
+            (Self::FinalTest, Language::English) => "Test",
+            (Self::Exam, Language::Ukrainian) => "Екз",
+            (Self::Exam, Language::English) => "Exam",
+            (Self::CourseWork, Language::Ukrainian) => "КП/КР",
+            (Self::CourseWork, Language::English) => "CP/CW",
+            (Self::Unknown(label), _) => label,
+        }
+    }
+}
+
+/// The upstream label, same as the Ukrainian [`EventKind::short_name`].
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.short_name(Language::Ukrainian))
+    }
+}
+
+/// Accepts identifiers, full and short names in both languages
+/// and the upstream abbreviations, ignoring case.
+/// Only the `unknown:` identifiers give [`EventKind::Unknown`], see [`EventKind::id`].
+impl FromStr for EventKind {
+    type Err = ParseEventKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(label) = s.strip_prefix(UNKNOWN_PREFIX) {
+            return Ok(Self::Unknown(label.to_owned()));
+        }
+        let lower = s.to_lowercase();
+
+        if let Some(kind) = Self::KNOWN.into_iter().find(|kind| {
+            lower == kind.id()
+                || [Language::Ukrainian, Language::English].iter().any(|&l| {
+                    lower == kind.name(l).to_lowercase()
+                        || lower == kind.short_name(l).to_lowercase()
+                })
+        }) {
+            return Ok(kind);
+        }
+
+        // Other spellings seen in the wild
+        Ok(match lower.as_str() {
+            "лекц" | "lect" => Self::Lecture,
+            "пр" | "практ" | "практика" | "practice" | "practical" => {
+                Self::PracticalWork
+            }
+            "лаб" | "лабораторна" | "laboratory" => Self::LaboratoryWork,
+            "consult" => Self::Consultation,
+            "зал." | "credit" => Self::FinalTest,
+            "екз." | "іспит" | "examination" => Self::Exam,
+            "кп"
+            | "кр"
+            | "кп / кр"
+            | "курсова"
+            | "курсовий проєкт"
+            | "курсова робота"
+            | "course work"
+            | "coursework"
+            | "cw" => Self::CourseWork,
+            _ => return Err(ParseEventKindError(s.to_owned())),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseEventKindError(pub String);
+
+impl std::fmt::Display for ParseEventKindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown event kind: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseEventKindError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_kind_display() {
+        assert_eq!(EventKind::Lecture.to_string(), "Лк");
+        assert_eq!(EventKind::Unknown("Сем".into()).to_string(), "Сем");
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(EventKind::Exam.name(Language::Ukrainian), "Екзамен");
+        assert_eq!(EventKind::Exam.short_name(Language::English), "Exam");
+        assert_eq!(EventKind::LaboratoryWork.id(), "laboratory");
+    }
+
+    #[test]
+    fn parse() {
+        for (s, kind) in [
+            ("Лк", EventKind::Lecture),
+            ("лекція", EventKind::Lecture),
+            ("Lecture", EventKind::Lecture),
+            ("Пз", EventKind::PracticalWork),
+            ("lab", EventKind::LaboratoryWork),
+            ("Конс", EventKind::Consultation),
+            (" Зал ", EventKind::FinalTest),
+            ("final_test", EventKind::FinalTest),
+            ("Екз", EventKind::Exam),
+            ("КП/КР", EventKind::CourseWork),
+            ("КР", EventKind::CourseWork),
+        ] {
+            assert_eq!(s.parse::<EventKind>(), Ok(kind), "{s}");
+        }
+        assert!("Сем".parse::<EventKind>().is_err());
+        assert_eq!(
+            EventKind::from_label("Сем"),
+            EventKind::Unknown("Сем".into())
+        );
+        // The user input only
+        for s in ["лекція", "КР", " Зал "] {
+            assert!(s.parse::<EventKind>().is_ok(), "{s}");
+            assert_eq!(EventKind::from_label(s), EventKind::Unknown(s.into()));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for kind in EventKind::KNOWN {
+            for language in [Language::Ukrainian, Language::English] {
+                assert_eq!(kind.name(language).parse(), Ok(kind.clone()));
+                assert_eq!(kind.short_name(language).parse(), Ok(kind.clone()));
+            }
+            assert_eq!(kind.id().parse(), Ok(kind.clone()));
+            assert_eq!(EventKind::from_label(&kind.to_string()), kind);
+        }
+
+        // Not the lecture
+        let unknown = EventKind::Unknown("lecture".into());
+        assert_eq!(unknown.id(), "unknown:lecture");
+        assert_eq!(unknown.id().parse(), Ok(unknown));
+        let unknown = EventKind::Unknown("Сем".into());
+        assert_eq!(unknown.id().parse(), Ok(unknown));
+    }
+}
@@ -1,4 +1,9 @@
+mod code;
+
+pub use code::{GroupCode, ParseGroupCodeError};
+
 use proc::PartialBorrow;
+use std::collections::BTreeMap;
 
 #[derive(Clone, Ord, PartialOrd, Debug, PartialBorrow)]
 pub struct Group {
@@ -8,3 +13,91 @@ pub struct Group {
     pub direction_id: Option<i32>,
     pub speciality_id: Option<i32>,
 }
+
+/// Groups by programme and entry year, see [`Group::tree`].
+pub type GroupTree<'a> = BTreeMap<String, BTreeMap<Option<u16>, Vec<&'a Group>>>;
+
+impl Group {
+    pub fn code(&self) -> Option<GroupCode> {
+        self.name.parse().ok()
+    }
+
+    /// Orders by the parsed code, so "ПЗПІ-23-2" goes before "ПЗПІ-23-10",
+    /// the names that can't be parsed go last by name.
+    pub fn cmp_by_code(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.code(), other.code()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+        .then_with(|| self.name.cmp(&other.name))
+    }
+
+    /// Groups by programme and entry year, each list ordered by code.
+    ///
+    /// The names that can't be parsed are keyed by the part before the first dash without a year.
+    pub fn tree<'a>(groups: impl IntoIterator<Item = &'a Self>) -> GroupTree<'a> {
+        let mut tree = GroupTree::new();
+        for group in groups {
+            let (programme, year) = match group.code() {
+                Some(code) => (code.programme, Some(code.year)),
+                None => {
+                    let prefix = group.name.split('-').next().unwrap_or_default();
+                    (prefix.trim().to_owned(), None)
+                }
+            };
+            tree.entry(programme)
+                .or_default()
+                .entry(year)
+                .or_default()
+                .push(group);
+        }
+        for groups in tree.values_mut().flat_map(BTreeMap::values_mut) {
+            groups.sort_by(|a, b| a.cmp_by_code(b));
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: i64, name: &str) -> Group {
+        Group {
+            id,
+            name: name.into(),
+            direction_id: None,
+            speciality_id: None,
+        }
+    }
+
+    #[test]
+    fn tree() {
+        let groups = [
+            group(1, "ПЗПІ-23-10"),
+            group(2, "ПЗПІ-23-2"),
+            group(3, "ПЗПІ-24-1"),
+            group(4, "КНТс-24-1"),
+            group(5, "Аспіранти"),
+        ];
+        let tree = Group::tree(&groups);
+
+        assert_eq!(
+            tree.keys().collect::<Vec<_>>(),
+            ["Аспіранти", "КНТ", "ПЗПІ"]
+        );
+        let ids = |year| -> Vec<i64> { tree["ПЗПІ"][&year].iter().map(|g| g.id).collect() };
+        assert_eq!(ids(Some(2023)), [2, 1]);
+        assert_eq!(ids(Some(2024)), [3]);
+        assert_eq!(tree["Аспіранти"][&None][0].id, 5);
+
+        let mut sorted = groups.to_vec();
+        sorted.sort_by(Group::cmp_by_code);
+        assert_eq!(
+            sorted.iter().map(|g| g.id).collect::<Vec<_>>(),
+            [4, 2, 1, 3, 5]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, NaiveDate};
+
+/// Parts of a NURE group name like "ПЗПІ-23-5" or "КНТс-24-1".
+///
+/// Ordered by programme, entry year, number and then markers.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GroupCode {
+    /// Educational programme abbreviation, e.g. "ПЗПІ".
+    pub programme: String,
+    /// Entry year, e.g. 2023.
+    pub year: u16,
+    pub number: u16,
+    /// Lowercase letters after the programme, e.g. "с" in "КНТс-24-1".
+    pub markers: String,
+    /// Anything after the number, e.g. "с" in "ПЗПІ-23-5с".
+    pub suffix: String,
+}
+
+impl GroupCode {
+    /// Shortened-term studies ("скорочений термін").
+    pub fn is_shortened(&self) -> bool {
+        self.has_marker(&['с', 'c'])
+    }
+
+    /// Groups of foreign students ("іноземці").
+    pub fn is_foreign(&self) -> bool {
+        self.has_marker(&['і', 'и', 'i'])
+    }
+
+    fn has_marker(&self, markers: &[char]) -> bool {
+        self.markers
+            .chars()
+            .chain(self.suffix.chars())
+            .any(|c| markers.contains(&c.to_lowercase().next().unwrap_or(c)))
+    }
+
+    /// Course (1-based) on the `date`, a new academic year starts on September 1.
+    /// `None` before the entry.
+    #[cfg(feature = "chrono")]
+    pub fn course(&self, date: NaiveDate) -> Option<u8> {
+        let academic_year = date.year() - i32::from(date.month() < 9);
+        let course = academic_year - i32::from(self.year) + 1;
+        u8::try_from(course).ok().filter(|c| *c > 0)
+    }
+}
+
+impl std::fmt::Display for GroupCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}-{:02}-{}{}",
+            self.programme,
+            self.markers,
+            self.year % 100,
+            self.number,
+            self.suffix
+        )
+    }
+}
+
+/// Accepts "ПРОГРАМА[маркери]-РР-N[суфікс]" with a two or four digit year.
+impl FromStr for GroupCode {
+    type Err = ParseGroupCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let error = || ParseGroupCodeError(s.to_owned());
+
+        let mut parts = s.splitn(3, '-');
+        let (Some(programme), Some(year), Some(number)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(error());
+        };
+
+        let split = programme
+            .find(|c: char| !c.is_uppercase())
+            .unwrap_or(programme.len());
+        let (programme, markers) = programme.split_at(split);
+        if programme.is_empty() || !markers.chars().all(char::is_lowercase) {
+            return Err(error());
+        }
+
+        let year = match year.len() {
+            2 => 2000 + year.parse::<u16>().map_err(|_| error())?,
+            4 => year.parse().map_err(|_| error())?,
+            _ => return Err(error()),
+        };
+
+        let split = number
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(number.len());
+        let (number, suffix) = number.split_at(split);
+
+        Ok(Self {
+            programme: programme.to_owned(),
+            year,
+            number: number.parse().map_err(|_| error())?,
+            markers: markers.to_owned(),
+            suffix: suffix.trim().to_owned(),
+        })
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ParseGroupCodeError(pub String);
+
+impl std::fmt::Display for ParseGroupCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown group name format: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseGroupCodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let code: GroupCode = "ПЗПІ-23-5".parse().unwrap();
+        assert_eq!(code.programme, "ПЗПІ");
+        assert_eq!((code.year, code.number), (2023, 5));
+        assert!(!code.is_shortened() && !code.is_foreign());
+        assert_eq!(code.to_string(), "ПЗПІ-23-5");
+
+        let shortened: GroupCode = "КНТс-24-1".parse().unwrap();
+        assert_eq!(
+            (shortened.programme.as_str(), shortened.markers.as_str()),
+            ("КНТ", "с")
+        );
+        assert!(shortened.is_shortened());
+        assert_eq!(shortened.to_string(), "КНТс-24-1");
+
+        let foreign: GroupCode = "ІТКН-22-2і".parse().unwrap();
+        assert_eq!(foreign.suffix, "і");
+        assert!(foreign.is_foreign());
+
+        for name in [
+            "",
+            "ПЗПІ",
+            "ПЗПІ-23",
+            "пзпі-23-5",
+            "ПЗПІ-2023x-5",
+            "ПЗПІ-23-",
+            "ПЗПІ-ab-1",
+        ] {
+            assert!(name.parse::<GroupCode>().is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn order() {
+        let mut codes: Vec<GroupCode> = ["ПЗПІ-23-10", "КІУКІ-24-1", "ПЗПІ-22-3", "ПЗПІ-23-2"]
+            .into_iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        codes.sort();
+        assert_eq!(
+            codes.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["КІУКІ-24-1", "ПЗПІ-22-3", "ПЗПІ-23-2", "ПЗПІ-23-10"]
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn course() {
+        let code: GroupCode = "ПЗПІ-23-5".parse().unwrap();
+        let date = |y, m| NaiveDate::from_ymd_opt(y, m, 1).unwrap();
+        assert_eq!(code.course(date(2023, 8)), None);
+        assert_eq!(code.course(date(2023, 9)), Some(1));
+        assert_eq!(code.course(date(2024, 6)), Some(1));
+        assert_eq!(code.course(date(2025, 10)), Some(3));
+    }
+}
@@ -0,0 +1,261 @@
+use crate::Language;
+
+use std::collections::BTreeSet;
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
+
+/// Official public holidays of Ukraine, as the Labour Code has them since 2017.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Holiday {
+    NewYear,
+    /// January 7, until 2023.
+    OrthodoxChristmas,
+    WomensDay,
+    /// Orthodox, by the Julian calendar.
+    Easter,
+    /// Orthodox, 49 days after Easter.
+    Trinity,
+    LabourDay,
+    /// May 8 since 2023, May 9 before.
+    VictoryDay,
+    ConstitutionDay,
+    /// Since 2022.
+    StatehoodDay,
+    IndependenceDay,
+    /// October 1 since 2023, October 14 before.
+    DefendersDay,
+    /// December 25.
+    Christmas,
+}
+
+impl Holiday {
+    pub const ALL: [Self; 12] = [
+        Self::NewYear,
+        Self::OrthodoxChristmas,
+        Self::WomensDay,
+        Self::Easter,
+        Self::Trinity,
+        Self::LabourDay,
+        Self::VictoryDay,
+        Self::ConstitutionDay,
+        Self::StatehoodDay,
+        Self::IndependenceDay,
+        Self::DefendersDay,
+        Self::Christmas,
+    ];
+
+    /// Date of the holiday in the `year`, `None` if there was no such holiday then.
+    pub fn date(self, year: i32) -> Option<NaiveDate> {
+        let (month, day) = match self {
+            Self::NewYear => (1, 1),
+            Self::OrthodoxChristmas if year <= 2023 => (1, 7),
+            Self::WomensDay => (3, 8),
+            Self::Easter => return orthodox_easter(year),
+            Self::Trinity => return Some(orthodox_easter(year)? + TimeDelta::days(49)),
+            Self::LabourDay => (5, 1),
+            Self::VictoryDay if year >= 2023 => (5, 8),
+            Self::VictoryDay => (5, 9),
+            Self::ConstitutionDay => (6, 28),
+            Self::StatehoodDay if year >= 2024 => (7, 15),
+            Self::StatehoodDay if year >= 2022 => (7, 28),
+            Self::IndependenceDay => (8, 24),
+            Self::DefendersDay if year >= 2023 => (10, 1),
+            Self::DefendersDay if year >= 2015 => (10, 14),
+            Self::Christmas if year >= 2017 => (12, 25),
+            _ => return None,
+        };
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    /// All the holidays of the `year` ordered by date.
+    pub fn in_year(year: i32) -> Vec<(NaiveDate, Self)> {
+        let mut holidays: Vec<_> = Self::ALL
+            .into_iter()
+            .filter_map(|h| h.date(year).map(|date| (date, h)))
+            .collect();
+        holidays.sort();
+        holidays
+    }
+
+    /// The holiday on the `date`, suspended or not.
+    pub fn on(date: NaiveDate) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|h| h.date(date.year()) == Some(date))
+    }
+
+    pub fn name(self, language: Language) -> &'static str {
+        match (self, language) {
+            (Self::NewYear, Language::Ukrainian) => "Новий рік",
+            (Self::NewYear, Language::English) => "New Year",
+            (Self::OrthodoxChristmas | Self::Christmas, Language::Ukrainian) => "Різдво Христове",
+            (Self::OrthodoxChristmas | Self::Christmas, Language::English) => "Christmas",
+            (Self::WomensDay, Language::Ukrainian) => "Міжнародний жіночий день",
+            (Self::WomensDay, Language::English) => "International Women's Day",
+            (Self::Easter, Language::Ukrainian) => "Великдень",
+            (Self::Easter, Language::English) => "Easter",
+            (Self::Trinity, Language::Ukrainian) => "Трійця",
+            (Self::Trinity, Language::English) => "Trinity",
+            (Self::LabourDay, Language::Ukrainian) => "День праці",
+            (Self::LabourDay, Language::English) => "Labour Day",
+            (Self::VictoryDay, Language::Ukrainian) => "День пам'яті та перемоги",
+            (Self::VictoryDay, Language::English) => "Day of Remembrance and Victory",
+            (Self::ConstitutionDay, Language::Ukrainian) => "День Конституції",
+            (Self::ConstitutionDay, Language::English) => "Constitution Day",
+            (Self::StatehoodDay, Language::Ukrainian) => "День Української Державності",
+            (Self::StatehoodDay, Language::English) => "Statehood Day",
+            (Self::IndependenceDay, Language::Ukrainian) => "День Незалежності",
+            (Self::IndependenceDay, Language::English) => "Independence Day",
+            (Self::DefendersDay, Language::Ukrainian) => "День захисників і захисниць",
+            (Self::DefendersDay, Language::English) => "Defenders Day",
+        }
+    }
+}
+
+/// Orthodox Easter Sunday (Gregorian date), valid for 1900-2099.
+fn orthodox_easter(year: i32) -> Option<NaiveDate> {
+    if !(1900..2100).contains(&year) {
+        return None;
+    }
+    // Meeus' Julian algorithm
+    let (a, b, c) = (year % 4, year % 7, year % 19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    let julian = NaiveDate::from_ymd_opt(year, month as u32, day as u32)?;
+    Some(julian + TimeDelta::days(13))
+}
+
+/// Non-working days.
+///
+/// ```rust
+/// use schedule_model::Holidays;
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let independence = NaiveDate::from_ymd_opt(2025, 8, 24).unwrap();
+/// assert!(Holidays::default().is_day_off(independence));
+///
+/// let martial_law = Holidays { suspended: true, ..Default::default() };
+/// assert!(!martial_law.is_day_off(independence));
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Holidays {
+    /// The holidays are working days, as under martial law since 2022.
+    pub suspended: bool,
+    /// Days off besides the official ones, e.g. declared by the university. Not suspended.
+    pub extra: BTreeSet<NaiveDate>,
+}
+
+impl Holidays {
+    /// Days off of the `year`: the holidays, the next working days for the ones
+    /// on weekends, and the extra days.
+    pub fn days_off(&self, year: i32) -> BTreeSet<NaiveDate> {
+        let mut days = BTreeSet::new();
+
+        if !self.suspended {
+            let holidays: Vec<_> = Holiday::in_year(year)
+                .into_iter()
+                .map(|(date, _)| date)
+                .collect();
+            days.extend(&holidays);
+            for date in holidays.into_iter().filter(|d| is_weekend(*d)) {
+                let transferred = date
+                    .iter_days()
+                    .find(|d| !is_weekend(*d) && !days.contains(d));
+                days.extend(transferred);
+            }
+        }
+
+        days.extend(self.extra.iter().filter(|d| d.year() == year));
+        days
+    }
+
+    pub fn is_day_off(&self, date: NaiveDate) -> bool {
+        self.extra.contains(&date) || self.days_off(date.year()).contains(&date)
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn easter() {
+        assert_eq!(orthodox_easter(2021), Some(date(2021, 5, 2)));
+        assert_eq!(orthodox_easter(2023), Some(date(2023, 4, 16)));
+        assert_eq!(orthodox_easter(2024), Some(date(2024, 5, 5)));
+        assert_eq!(orthodox_easter(2025), Some(date(2025, 4, 20)));
+        assert_eq!(orthodox_easter(2026), Some(date(2026, 4, 12)));
+        assert_eq!(Holiday::Trinity.date(2024), Some(date(2024, 6, 23)));
+    }
+
+    #[test]
+    fn years() {
+        let dates = |year| {
+            Holiday::in_year(year)
+                .into_iter()
+                .map(|(d, _)| (d.month(), d.day()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            dates(2021),
+            [
+                (1, 1),
+                (1, 7),
+                (3, 8),
+                (5, 1),
+                (5, 2),
+                (5, 9),
+                (6, 20),
+                (6, 28),
+                (8, 24),
+                (10, 14),
+                (12, 25)
+            ]
+        );
+        assert_eq!(
+            dates(2024),
+            [
+                (1, 1),
+                (3, 8),
+                (5, 1),
+                (5, 5),
+                (5, 8),
+                (6, 23),
+                (6, 28),
+                (7, 15),
+                (8, 24),
+                (10, 1),
+                (12, 25)
+            ]
+        );
+    }
+
+    #[test]
+    fn days_off() {
+        // May 1 is on Saturday and Easter on Sunday
+        let holidays = Holidays::default();
+        assert!(holidays.is_day_off(date(2021, 5, 3)));
+        assert!(holidays.is_day_off(date(2021, 5, 4)));
+        assert!(!holidays.is_day_off(date(2021, 5, 5)));
+
+        let mut martial_law = Holidays {
+            suspended: true,
+            ..Default::default()
+        };
+        martial_law.extra.insert(date(2025, 12, 31));
+        assert!(!martial_law.is_day_off(date(2025, 12, 25)));
+        assert!(martial_law.is_day_off(date(2025, 12, 31)));
+        assert_eq!(Holiday::on(date(2025, 12, 25)), Some(Holiday::Christmas));
+        assert_eq!(martial_law.days_off(2025).len(), 1);
+    }
+}
@@ -0,0 +1,7 @@
+/// Language of the names shown to users.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum Language {
+    #[default]
+    Ukrainian,
+    English,
+}
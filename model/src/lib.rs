@@ -1,14 +1,37 @@
+pub mod analysis;
 mod auditorium;
+#[cfg(feature = "chrono")]
+mod calendar;
 mod event;
 mod group;
+#[cfg(feature = "chrono")]
+mod holiday;
+mod lang;
+#[cfg(feature = "chrono")]
+mod pair;
+pub mod search;
 mod subject;
 mod teacher;
+#[cfg(feature = "chrono")]
+pub mod time;
+mod timetable;
 
-pub use auditorium::Auditorium;
-pub use event::{Event, EventKind};
-pub use group::Group;
+pub use auditorium::{Auditorium, AuditoriumName, Location, ONLINE_AUDITORIUMS};
+#[cfg(feature = "chrono")]
+pub use calendar::{AcademicCalendar, Semester, WeekParity};
+pub use event::{Event, EventKind, ParseEventKindError};
+pub use group::{Group, GroupCode, GroupTree, ParseGroupCodeError};
+#[cfg(feature = "chrono")]
+pub use holiday::{Holiday, Holidays};
+pub use lang::Language;
+#[cfg(feature = "chrono")]
+pub use pair::{PairMismatch, PairSchedule, PairSlot, PairTables};
 pub use subject::Subject;
-pub use teacher::Teacher;
+pub use teacher::{Teacher, TeacherName};
+pub use timetable::{EventChange, ModifiedEvent, Timetable, TimetableDiff, TimetableKind};
+
+#[cfg(feature = "chrono")]
+pub use {chrono, chrono_tz};
 
 use std::collections::HashSet;
 
@@ -16,9 +39,3 @@ pub type Groups = HashSet<Group>;
 pub type Teachers = HashSet<Teacher>;
 pub type Subjects = HashSet<Subject>;
 pub type Auditoriums = HashSet<Auditorium>;
-
-#[derive(Clone, Eq, PartialEq, Debug, Default)]
-pub struct Timetable {
-    pub events: HashSet<Event>,
-    pub subjects: HashSet<Subject>,
-}
@@ -0,0 +1,302 @@
+use crate::{
+    Event,
+    time::{KYIV, local_timestamp},
+};
+
+use std::ops::RangeInclusive;
+
+use chrono::{NaiveDate, NaiveTime};
+
+/// Local start and end of a pair (class slot) with the given number.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PairSlot {
+    pub number: u8,
+    pub starts: NaiveTime,
+    pub ends: NaiveTime,
+}
+
+impl PairSlot {
+    pub fn new(number: u8, starts: (u32, u32), ends: (u32, u32)) -> Option<Self> {
+        Some(Self {
+            number,
+            starts: NaiveTime::from_hms_opt(starts.0, starts.1, 0)?,
+            ends: NaiveTime::from_hms_opt(ends.0, ends.1, 0)?,
+        })
+    }
+
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        self.starts <= time && time < self.ends
+    }
+
+    /// Unix timestamps of the start and end on the local `date`.
+    pub fn on(&self, date: NaiveDate) -> (i64, i64) {
+        (
+            local_timestamp(date, self.starts),
+            local_timestamp(date, self.ends),
+        )
+    }
+}
+
+/// Bell schedule, maps pair numbers to their time.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PairSchedule {
+    slots: Vec<PairSlot>, // sorted by start
+}
+
+impl Default for PairSchedule {
+    fn default() -> Self {
+        Self::nure()
+    }
+}
+
+impl PairSchedule {
+    pub fn new(slots: impl IntoIterator<Item = PairSlot>) -> Self {
+        let mut slots = Vec::from_iter(slots);
+        slots.sort_by_key(|s| (s.starts, s.number));
+        Self { slots }
+    }
+
+    /// The regular NURE bell schedule.
+    pub fn nure() -> Self {
+        Self::new(
+            [
+                (1, (7, 45), (9, 20)),
+                (2, (9, 30), (11, 5)),
+                (3, (11, 15), (12, 50)),
+                (4, (13, 10), (14, 45)),
+                (5, (14, 55), (16, 30)),
+                (6, (16, 40), (18, 15)),
+                (7, (18, 25), (20, 0)),
+                (8, (20, 10), (21, 45)),
+            ]
+            .into_iter()
+            .filter_map(|(n, s, e)| PairSlot::new(n, s, e)),
+        )
+    }
+
+    pub fn slots(&self) -> &[PairSlot] {
+        &self.slots
+    }
+
+    /// Slot of the pair `number`.
+    pub fn get(&self, number: u8) -> Option<&PairSlot> {
+        self.slots.iter().find(|s| s.number == number)
+    }
+
+    /// Slot that is going on at the local `time`.
+    pub fn at(&self, time: NaiveTime) -> Option<&PairSlot> {
+        self.slots.iter().find(|s| s.contains(time))
+    }
+
+    /// Slot that is going on at the `timestamp`.
+    pub fn at_timestamp(&self, timestamp: i64) -> Option<&PairSlot> {
+        self.at(crate::time::instant(timestamp).with_timezone(&KYIV).time())
+    }
+
+    /// Nearest slot that starts at or after the local `time`.
+    pub fn next(&self, time: NaiveTime) -> Option<&PairSlot> {
+        self.slots.iter().find(|s| s.starts >= time)
+    }
+
+    /// Check that `event` takes exactly the time of its pair number (`count`).
+    pub fn validate(&self, event: &Event) -> Result<(), PairMismatch> {
+        let Some(&expected) = self.get(event.count) else {
+            return Err(PairMismatch::UnknownPair {
+                event: event.id,
+                number: event.count,
+            });
+        };
+
+        let starts = event.local_start().time();
+        let ends = event.local_end().time();
+
+        if expected.starts == starts && expected.ends == ends {
+            Ok(())
+        } else {
+            Err(PairMismatch::Time {
+                event: event.id,
+                expected,
+                starts,
+                ends,
+            })
+        }
+    }
+}
+
+/// Regular bell schedule with alternative ones for some dates,
+/// e.g. exam periods or shortened days.
+///
+/// ```rust
+/// use schedule_model::{PairSchedule, PairSlot, PairTables};
+/// use schedule_model::chrono::NaiveDate;
+///
+/// let short = PairSchedule::new(PairSlot::new(1, (8, 0), (9, 0)));
+/// let day = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+///
+/// let tables = PairTables::default().with(day..=day, short.clone());
+/// assert_eq!(tables.for_date(day), &short);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PairTables {
+    regular: PairSchedule,
+    periods: Vec<(RangeInclusive<NaiveDate>, PairSchedule)>,
+}
+
+impl PairTables {
+    pub fn new(regular: PairSchedule) -> Self {
+        Self {
+            regular,
+            periods: Vec::new(),
+        }
+    }
+
+    /// Use `schedule` for the `dates`, later periods take precedence.
+    #[must_use]
+    pub fn with(mut self, dates: RangeInclusive<NaiveDate>, schedule: PairSchedule) -> Self {
+        self.periods.push((dates, schedule));
+        self
+    }
+
+    pub fn regular(&self) -> &PairSchedule {
+        &self.regular
+    }
+
+    pub fn for_date(&self, date: NaiveDate) -> &PairSchedule {
+        self.periods
+            .iter()
+            .rev()
+            .find_map(|(dates, schedule)| dates.contains(&date).then_some(schedule))
+            .unwrap_or(&self.regular)
+    }
+
+    /// Slot that is going on at the `timestamp`.
+    pub fn at_timestamp(&self, timestamp: i64) -> Option<&PairSlot> {
+        self.for_date(crate::time::local_date(timestamp))
+            .at_timestamp(timestamp)
+    }
+
+    pub fn validate(&self, event: &Event) -> Result<(), PairMismatch> {
+        self.for_date(event.date()).validate(event)
+    }
+
+    /// All the events that disagree with their pair number, in no particular order.
+    pub fn validate_all<'a>(
+        &self,
+        events: impl IntoIterator<Item = &'a Event>,
+    ) -> Vec<PairMismatch> {
+        events
+            .into_iter()
+            .filter_map(|e| self.validate(e).err())
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PairMismatch {
+    /// The bell schedule has no such pair.
+    UnknownPair { event: i64, number: u8 },
+    /// The event takes other (local) time than its pair.
+    Time {
+        event: i64,
+        expected: PairSlot,
+        starts: NaiveTime,
+        ends: NaiveTime,
+    },
+}
+
+impl PairMismatch {
+    pub fn event(&self) -> i64 {
+        match self {
+            Self::UnknownPair { event, .. } | Self::Time { event, .. } => *event,
+        }
+    }
+}
+
+impl std::fmt::Display for PairMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownPair { event, number } => {
+                write!(f, "Event {event} has unknown pair number {number}")
+            }
+            Self::Time {
+                event,
+                expected,
+                starts,
+                ends,
+            } => write!(
+                f,
+                "Event {event} takes {starts}-{ends} instead of {}-{} (pair {})",
+                expected.starts, expected.ends, expected.number
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PairMismatch {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn lookup() {
+        let pairs = PairSchedule::nure();
+
+        assert_eq!(pairs.get(3).map(|s| s.starts), Some(time(11, 15)));
+        assert_eq!(pairs.at(time(13, 10)).map(|s| s.number), Some(4));
+        assert_eq!(pairs.at(time(12, 55)), None); // break
+        assert_eq!(pairs.next(time(12, 55)).map(|s| s.number), Some(4));
+        assert_eq!(pairs.at(time(21, 50)), None);
+
+        // Monday 2025-09-15 07:45 EEST
+        assert_eq!(pairs.at_timestamp(1757911500).map(|s| s.number), Some(1));
+    }
+
+    #[test]
+    fn slot_timestamps_dst() {
+        let first = *PairSchedule::nure().get(1).unwrap();
+        let (summer, _) = first.on(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap());
+        let (winter, _) = first.on(NaiveDate::from_ymd_opt(2025, 3, 29).unwrap());
+        assert_eq!(summer, 1743396300);
+        assert_eq!(winter, 1743227100);
+    }
+
+    #[test]
+    fn validate() {
+        let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+        let pairs = PairSchedule::nure();
+        let second = |id| Event::test_pair(id, monday, 2);
+
+        assert_eq!(pairs.validate(&second(1)), Ok(()));
+        assert!(matches!(
+            pairs.validate(&second(2).count(3)),
+            Err(PairMismatch::Time { event: 2, expected, .. }) if expected.number == 3
+        ));
+        assert_eq!(
+            pairs.validate(&second(3).count(9)),
+            Err(PairMismatch::UnknownPair {
+                event: 3,
+                number: 9
+            })
+        );
+    }
+
+    #[test]
+    fn alternative_tables() {
+        let exam_day = NaiveDate::from_ymd_opt(2026, 1, 12).unwrap();
+        let exams = PairSchedule::new(PairSlot::new(1, (9, 0), (12, 0)));
+        let tables = PairTables::default().with(exam_day..=exam_day, exams);
+
+        let exam = Event::test(1, 1768201200).lasting(3 * 60 * 60); // 09:00-12:00 EET
+        assert_eq!(tables.validate(&exam), Ok(()));
+        assert_eq!(
+            tables.regular().validate(&exam).map_err(|e| e.event()),
+            Err(1)
+        );
+        assert_eq!(tables.validate_all([&exam, &exam]), []);
+    }
+}
@@ -0,0 +1,378 @@
+mod translit;
+
+pub use translit::{switch_layout, transliterate};
+
+use crate::{Auditorium, Group, Subject, Teacher};
+
+/// What a search hit refers to.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum SearchEntity {
+    Group(i64),
+    Teacher(i64),
+    Subject(i64),
+    Auditorium(i64),
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SearchHit {
+    pub entity: SearchEntity,
+    /// Name to show.
+    pub label: String,
+    /// The higher the better, only relative to the other hits of the same query.
+    pub score: u32,
+}
+
+/// Searchable form of a name: lowercase words without punctuation.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct Key {
+    words: Vec<String>,
+    compact: String,
+}
+
+impl Key {
+    fn new(s: &str) -> Self {
+        let words: Vec<String> = s
+            .split(|c: char| !c.is_alphanumeric() && !is_apostrophe(c))
+            .map(|w| {
+                w.chars()
+                    .filter(|c| !is_apostrophe(*c))
+                    .flat_map(char::to_lowercase)
+                    .collect::<String>()
+            })
+            .filter(|w| !w.is_empty())
+            .collect();
+        Self {
+            compact: words.concat(),
+            words,
+        }
+    }
+
+    /// First letters of the words, e.g. "ооп" for "Об'єктно-орієнтоване програмування".
+    fn initials(&self) -> Option<Self> {
+        if self.words.len() < 2 {
+            return None;
+        }
+        let initials: String = self.words.iter().filter_map(|w| w.chars().next()).collect();
+        Some(Self {
+            words: vec![initials.clone()],
+            compact: initials,
+        })
+    }
+
+    fn score(&self, query: &Self) -> u32 {
+        let q = &query.compact;
+        if q.is_empty() {
+            return 0;
+        }
+        if self.compact == *q {
+            return 1000;
+        }
+        if self.compact.starts_with(q.as_str()) {
+            return 800;
+        }
+        // Every query word starts some word, e.g. "шев тар" or "тарас шевченко"
+        if query
+            .words
+            .iter()
+            .all(|q| self.words.iter().any(|w| w.starts_with(q.as_str())))
+        {
+            return 700;
+        }
+        if self.compact.contains(q.as_str()) {
+            return 500;
+        }
+
+        // Typos in the beginning of the name
+        let len = q.chars().count();
+        if len < 3 {
+            return 0;
+        }
+        let max = len / 4 + 1;
+        let prefix: String = self.compact.chars().take(len).collect();
+        let distance = [prefix.as_str(), self.compact.as_str()]
+            .into_iter()
+            .chain(self.words.iter().map(String::as_str))
+            .map(|k| distance(k, q))
+            .min()
+            .unwrap_or(usize::MAX);
+        if distance <= max {
+            return 300u32.saturating_sub(u32::try_from(distance * 60).unwrap_or(u32::MAX));
+        }
+        0
+    }
+}
+
+fn is_apostrophe(c: char) -> bool {
+    matches!(c, '\'' | '’' | 'ʼ' | '`')
+}
+
+/// Optimal string alignment distance (Levenshtein with transpositions) by characters.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>(); a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut d = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d = d.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = d;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    entity: SearchEntity,
+    label: String,
+    /// In Cyrillic and transliterated.
+    keys: Vec<Key>,
+}
+
+/// In-memory fuzzy search by names.
+///
+/// Queries may be partial, transliterated to Latin, typed in the wrong keyboard layout,
+/// or the abbreviations of the subjects and the short names of the teachers.
+///
+/// ```rust
+/// use schedule_model::{Teacher, search::{SearchEntity, SearchIndex}};
+///
+/// let teacher = Teacher::new(1, "Шевченко Тарас Григорович".into(), None);
+/// let index = SearchIndex::new().teachers([&teacher]);
+///
+/// for query in ["Шевч", "shevchenko", "itdxtyrj", "Шевченко Т. Г.", "Шевчеко"] {
+///     assert_eq!(index.search(query, 10)[0].entity, SearchEntity::Teacher(1), "{query}");
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SearchIndex {
+    entries: Vec<Entry>,
+}
+
+impl SearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn groups<'a>(mut self, groups: impl IntoIterator<Item = &'a Group>) -> Self {
+        for g in groups {
+            self.insert(SearchEntity::Group(g.id), &g.name, [g.name.as_str()]);
+        }
+        self
+    }
+
+    pub fn teachers<'a>(mut self, teachers: impl IntoIterator<Item = &'a Teacher>) -> Self {
+        for t in teachers {
+            self.insert(
+                SearchEntity::Teacher(t.id),
+                &t.name,
+                [t.name.as_str(), t.abbr.as_str()],
+            );
+        }
+        self
+    }
+
+    pub fn subjects<'a>(mut self, subjects: impl IntoIterator<Item = &'a Subject>) -> Self {
+        for s in subjects {
+            self.insert(
+                SearchEntity::Subject(s.id),
+                &s.name,
+                [s.name.as_str(), s.abbr.as_str()],
+            );
+        }
+        self
+    }
+
+    pub fn auditoriums<'a>(
+        mut self,
+        auditoriums: impl IntoIterator<Item = &'a Auditorium>,
+    ) -> Self {
+        for a in auditoriums {
+            self.insert(SearchEntity::Auditorium(a.id), &a.name, [a.name.as_str()]);
+        }
+        self
+    }
+
+    /// Adds an entity found by any of the `names`, replacing the previous one.
+    pub fn insert<'a>(
+        &mut self,
+        entity: SearchEntity,
+        label: &str,
+        names: impl IntoIterator<Item = &'a str>,
+    ) {
+        let mut keys = Vec::new();
+        for name in names {
+            for key in [Key::new(name), Key::new(&transliterate(name))] {
+                keys.extend(key.initials());
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        self.entries.retain(|e| e.entity != entity);
+        self.entries.push(Entry {
+            entity,
+            label: label.to_owned(),
+            keys,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Up to `limit` hits, the best first, then the shorter names.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        // (key, penalty) for the query as is and retyped in the other layout
+        let switched = switch_layout(query);
+        let queries = [
+            (Key::new(query), 0),
+            (Key::new(&transliterate(query)), 10),
+            (Key::new(&switched), 50),
+            (Key::new(&transliterate(&switched)), 60),
+        ];
+
+        let mut hits: Vec<SearchHit> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let score = entry
+                    .keys
+                    .iter()
+                    .flat_map(|key| {
+                        queries
+                            .iter()
+                            .map(|(query, penalty)| match key.score(query) {
+                                0 => 0,
+                                score => score.saturating_sub(*penalty),
+                            })
+                    })
+                    .max()
+                    .filter(|score| *score > 0)?;
+                Some(SearchHit {
+                    entity: entry.entity,
+                    label: entry.label.clone(),
+                    score,
+                })
+            })
+            .collect();
+
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.label.chars().count().cmp(&b.label.chars().count()))
+                .then_with(|| a.label.cmp(&b.label))
+        });
+        hits.truncate(limit);
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SearchIndex {
+        let groups = [
+            Group {
+                id: 1,
+                name: "ПЗПІ-23-5".into(),
+                direction_id: None,
+                speciality_id: None,
+            },
+            Group {
+                id: 2,
+                name: "ПЗПІ-23-10".into(),
+                direction_id: None,
+                speciality_id: None,
+            },
+        ];
+        let teachers = [
+            Teacher::new(1, "Шевченко Тарас Григорович".into(), None),
+            Teacher::new(2, "Шевчук Ольга Петрівна".into(), None),
+        ];
+        let subjects = [Subject {
+            id: 1,
+            abbr: "ООП".into(),
+            name: "Об'єктно-орієнтоване програмування".into(),
+        }];
+        let auditoriums = [Auditorium {
+            id: 1,
+            name: "287".into(),
+            floor: 2,
+            power: true,
+            building: "г".into(),
+        }];
+        SearchIndex::new()
+            .groups(&groups)
+            .teachers(&teachers)
+            .subjects(&subjects)
+            .auditoriums(&auditoriums)
+    }
+
+    fn first(index: &SearchIndex, query: &str) -> Option<SearchEntity> {
+        index.search(query, 10).first().map(|h| h.entity)
+    }
+
+    #[test]
+    fn ranked() {
+        let index = index();
+        assert_eq!(index.len(), 6);
+
+        let hits = index.search("шевч", 10);
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].entity, SearchEntity::Teacher(2)); // shorter
+        assert_eq!(first(&index, "шевченко"), Some(SearchEntity::Teacher(1)));
+        assert_eq!(
+            first(&index, "Тарас Шевченко"),
+            Some(SearchEntity::Teacher(1))
+        );
+
+        assert_eq!(first(&index, "ПЗПІ 23 5"), Some(SearchEntity::Group(1)));
+        assert_eq!(first(&index, "пзпі-23-10"), Some(SearchEntity::Group(2)));
+        assert_eq!(index.search("пзпі", 10).len(), 2);
+        assert_eq!(first(&index, "287"), Some(SearchEntity::Auditorium(1)));
+        assert_eq!(index.search("xyzzy", 10), []);
+        assert_eq!(index.search("", 10), []);
+        assert_eq!(index.search("шевч", 1).len(), 1);
+    }
+
+    #[test]
+    fn fuzzy() {
+        let index = index();
+        // Transliterated and in the wrong layout
+        assert_eq!(first(&index, "pzpi-23-5"), Some(SearchEntity::Group(1)));
+        assert_eq!(first(&index, "itdxtyrj"), Some(SearchEntity::Teacher(1)));
+        assert_eq!(first(&index, "gpgs-23-5"), Some(SearchEntity::Group(1)));
+        // Typos
+        assert_eq!(first(&index, "Шевчекно"), Some(SearchEntity::Teacher(1)));
+        assert_eq!(first(&index, "shevchemko"), Some(SearchEntity::Teacher(1)));
+        // Abbreviations and short names
+        assert_eq!(first(&index, "ооп"), Some(SearchEntity::Subject(1)));
+        assert_eq!(first(&index, "jjg"), Some(SearchEntity::Subject(1)));
+        assert_eq!(
+            first(&index, "Шевчук О. П."),
+            Some(SearchEntity::Teacher(2))
+        );
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(distance("шевченко", "шевченко"), 0);
+        assert_eq!(distance("шевченко", "шевчекно"), 1);
+        assert_eq!(distance("abc", ""), 3);
+        assert_eq!(distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,137 @@
+/// Ukrainian to Latin by the national system (KMU 2010), lowercase.
+///
+/// "Є", "Ї", "Й", "Ю" and "Я" at the start of a word become "ye", "yi", "y", "yu" and "ya".
+/// Other characters are kept.
+pub fn transliterate(s: &str) -> String {
+    let mut latin = String::with_capacity(s.len());
+    let mut word_start = true;
+    for c in s.chars().flat_map(char::to_lowercase) {
+        let l = match (c, word_start) {
+            ('є', true) => "ye",
+            ('ї', true) => "yi",
+            ('й', true) => "y",
+            ('ю', true) => "yu",
+            ('я', true) => "ya",
+            ('а', _) => "a",
+            ('б', _) => "b",
+            ('в', _) => "v",
+            ('г', _) => "h",
+            ('ґ', _) => "g",
+            ('д', _) => "d",
+            ('е' | 'э', _) => "e",
+            ('є', _) => "ie",
+            ('ж', _) => "zh",
+            ('з', _) => "z",
+            ('и' | 'ы', _) => "y",
+            ('і' | 'ї' | 'й', _) => "i",
+            ('к', _) => "k",
+            ('л', _) => "l",
+            ('м', _) => "m",
+            ('н', _) => "n",
+            ('о', _) => "o",
+            ('п', _) => "p",
+            ('р', _) => "r",
+            ('с', _) => "s",
+            ('т', _) => "t",
+            ('у', _) => "u",
+            ('ф', _) => "f",
+            ('х', _) => "kh",
+            ('ц', _) => "ts",
+            ('ч', _) => "ch",
+            ('ш', _) => "sh",
+            ('щ', _) => "shch",
+            ('ю', _) => "iu",
+            ('я', _) => "ia",
+            ('ё', _) => "io",
+            ('ь' | 'ъ' | '\'' | '’' | 'ʼ', _) => "",
+            _ => {
+                latin.push(c);
+                word_start = !c.is_alphanumeric();
+                continue;
+            }
+        };
+        latin.push_str(l);
+        word_start = false;
+    }
+    latin
+}
+
+/// Keys of the Ukrainian layout in the order of the QWERTY ones.
+const LAYOUT: [(char, char); 34] = [
+    ('q', 'й'),
+    ('w', 'ц'),
+    ('e', 'у'),
+    ('r', 'к'),
+    ('t', 'е'),
+    ('y', 'н'),
+    ('u', 'г'),
+    ('i', 'ш'),
+    ('o', 'щ'),
+    ('p', 'з'),
+    ('[', 'х'),
+    (']', 'ї'),
+    ('a', 'ф'),
+    ('s', 'і'),
+    ('d', 'в'),
+    ('f', 'а'),
+    ('g', 'п'),
+    ('h', 'р'),
+    ('j', 'о'),
+    ('k', 'л'),
+    ('l', 'д'),
+    (';', 'ж'),
+    ('\'', 'є'),
+    ('z', 'я'),
+    ('x', 'ч'),
+    ('c', 'с'),
+    ('v', 'м'),
+    ('b', 'и'),
+    ('n', 'т'),
+    ('m', 'ь'),
+    (',', 'б'),
+    ('.', 'ю'),
+    ('`', '\''),
+    ('\\', 'ґ'),
+];
+
+/// Retypes the text as if it was typed in the other layout (QWERTY or Ukrainian ЙЦУКЕН),
+/// e.g. "itdxtyrj" becomes "шевченко", lowercase.
+pub fn switch_layout(s: &str) -> String {
+    s.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| {
+            LAYOUT
+                .iter()
+                .find_map(|&(en, uk)| {
+                    if c == en {
+                        Some(uk)
+                    } else if c == uk {
+                        Some(en)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(c)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latin() {
+        assert_eq!(transliterate("Шевченко Тарас"), "shevchenko taras");
+        assert_eq!(transliterate("Юлія Їжакевич"), "yuliia yizhakevych");
+        assert_eq!(transliterate("Д'яченко Щука"), "diachenko shchuka");
+        assert_eq!(transliterate("ПЗПІ-23-5"), "pzpi-23-5");
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(switch_layout("itdxtyrj"), "шевченко");
+        assert_eq!(switch_layout("Шевченко"), "itdxtyrj");
+        assert_eq!(switch_layout("gpgs-23"), "пзпі-23");
+    }
+}
@@ -1,10 +1,51 @@
+mod name;
+
+pub use name::TeacherName;
+
 use proc::PartialBorrow;
 
 #[derive(Clone, Ord, PartialOrd, Debug, PartialBorrow)]
 pub struct Teacher {
     #[borrow_id]
     pub id: i64,
+    /// Short form of the name, "Прізвище І. Б.".
     pub abbr: String,
     pub name: String,
     pub department_id: Option<i32>,
 }
+
+impl Teacher {
+    /// Derives the short name from the full `name`.
+    pub fn new(id: i64, name: String, department_id: Option<i32>) -> Self {
+        Self {
+            id,
+            abbr: TeacherName::parse(&name).short(),
+            name,
+            department_id,
+        }
+    }
+
+    /// Like [`Self::new`], but takes the `short_name` for the name if the full one is blank.
+    pub fn with_short_name(
+        id: i64,
+        name: String,
+        short_name: String,
+        department_id: Option<i32>,
+    ) -> Self {
+        if name.trim().is_empty() {
+            Self::new(id, short_name, department_id)
+        } else {
+            Self::new(id, name, department_id)
+        }
+    }
+
+    pub fn parsed_name(&self) -> TeacherName {
+        TeacherName::parse(&self.name)
+    }
+}
+
+impl std::fmt::Display for Teacher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.abbr)
+    }
+}
@@ -0,0 +1,149 @@
+/// Full name of a person in the Ukrainian order: surname, given name(s), patronymic.
+///
+/// ```rust
+/// use schedule_model::TeacherName;
+///
+/// let name = TeacherName::parse("Петренко-Сидоренко Анна-Марія Іванівна");
+/// assert_eq!(name.short(), "Петренко-Сидоренко А.-М. І.");
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct TeacherName {
+    pub surname: String,
+    /// One or several (foreign names) given names, may be initials already.
+    pub given: Vec<String>,
+    pub patronymic: Option<String>,
+}
+
+/// Particles starting foreign surnames, e.g. "ван дер Берг".
+const PARTICLES: [&str; 12] = [
+    "ван", "фон", "де", "дер", "ді", "да", "ла", "ле", "дю", "дель", "ель", "аль",
+];
+
+/// Endings of the Ukrainian (and Russian) patronymics.
+const PATRONYMICS: [&str; 9] = [
+    "ович",
+    "евич",
+    "йович",
+    "ьович",
+    "ич",
+    "івна",
+    "ївна",
+    "овна",
+    "ична",
+];
+
+impl TeacherName {
+    /// Never fails, the first word (with the leading particles) is the surname.
+    pub fn parse(full_name: &str) -> Self {
+        let mut words = full_name.split_whitespace().peekable();
+
+        let mut surname = Vec::new();
+        while let Some(word) = words.next_if(|w| PARTICLES.contains(&w.to_lowercase().as_str())) {
+            surname.push(word);
+        }
+        surname.extend(words.next());
+
+        let mut given: Vec<String> = words.map(str::to_owned).collect();
+        let patronymic = match given.last() {
+            Some(last) if given.len() > 1 && is_patronymic(last) => given.pop(),
+            // "Іваненко П. О."
+            Some(_) if given.len() > 1 && given.iter().all(|w| is_initial(w)) => given.pop(),
+            _ => None,
+        };
+
+        Self {
+            surname: surname.join(" "),
+            given,
+            patronymic,
+        }
+    }
+
+    /// "Прізвище І. Б.", only the surname if there is nothing else.
+    pub fn short(&self) -> String {
+        std::iter::once(self.surname.clone())
+            .chain(
+                self.given
+                    .iter()
+                    .chain(&self.patronymic)
+                    .map(|w| initials(w)),
+            )
+            .filter(|w| !w.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl std::fmt::Display for TeacherName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let words = std::iter::once(&self.surname)
+            .chain(&self.given)
+            .chain(&self.patronymic)
+            .filter(|w| !w.is_empty());
+        for (i, word) in words.enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(word)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_patronymic(word: &str) -> bool {
+    let word = word.to_lowercase();
+    PATRONYMICS.iter().any(|ending| word.ends_with(ending))
+}
+
+fn is_initial(word: &str) -> bool {
+    word.ends_with('.')
+}
+
+/// "Анна-Марія" -> "А.-М.", already abbreviated "О." stays the same.
+fn initials(word: &str) -> String {
+    word.split('-')
+        .filter_map(|part| {
+            part.chars()
+                .find(|c| c.is_alphabetic())
+                .map(|c| format!("{}.", c.to_uppercase()))
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ukrainian() {
+        let name = TeacherName::parse("  Шевченко  Тарас Григорович ");
+        assert_eq!(name.surname, "Шевченко");
+        assert_eq!(name.given, ["Тарас"]);
+        assert_eq!(name.patronymic.as_deref(), Some("Григорович"));
+        assert_eq!(name.short(), "Шевченко Т. Г.");
+        assert_eq!(name.to_string(), "Шевченко Тарас Григорович");
+
+        assert_eq!(
+            TeacherName::parse("Д'яченко Юлія Євгенівна").short(),
+            "Д'яченко Ю. Є."
+        );
+        assert_eq!(
+            TeacherName::parse("Іваненко П. О.").short(),
+            "Іваненко П. О."
+        );
+        assert_eq!(TeacherName::parse("Українка Леся").short(), "Українка Л.");
+        assert_eq!(TeacherName::parse("Вакансія").short(), "Вакансія");
+        assert_eq!(TeacherName::parse("").short(), "");
+    }
+
+    #[test]
+    fn foreign() {
+        let name = TeacherName::parse("ван дер Берг Ян Пітер");
+        assert_eq!(name.surname, "ван дер Берг");
+        assert_eq!(name.given, ["Ян", "Пітер"]);
+        assert_eq!(name.patronymic, None);
+        assert_eq!(name.short(), "ван дер Берг Я. П.");
+
+        assert_eq!(TeacherName::parse("Smith John").short(), "Smith J.");
+    }
+}
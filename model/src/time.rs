@@ -0,0 +1,120 @@
+use crate::Event;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// The time zone of the university, local dates and times are in it unless stated otherwise.
+pub const KYIV: Tz = chrono_tz::Europe::Kyiv;
+
+/// Unix timestamp to a UTC instant.
+pub fn instant(timestamp: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(timestamp, 0).unwrap_or_default() // out of range is garbage anyway
+}
+
+/// Unix timestamp of the local (Kyiv) `date` and `time`.
+///
+/// The repeated hour in autumn resolves to its first occurrence,
+/// the skipped hour in spring is shifted forward by the hour.
+pub fn local_timestamp(date: NaiveDate, time: NaiveTime) -> i64 {
+    let naive = date.and_time(time);
+    KYIV.from_local_datetime(&naive)
+        .earliest()
+        .or_else(|| {
+            KYIV.from_local_datetime(&(naive + TimeDelta::hours(1)))
+                .earliest()
+        })
+        .map_or_else(|| naive.and_utc().timestamp(), |dt| dt.timestamp())
+}
+
+/// Local (Kyiv) date of the timestamp.
+pub fn local_date(timestamp: i64) -> NaiveDate {
+    instant(timestamp).with_timezone(&KYIV).date_naive()
+}
+
+impl Event {
+    pub fn start(&self) -> DateTime<Utc> {
+        instant(self.starts_at)
+    }
+    pub fn end(&self) -> DateTime<Utc> {
+        instant(self.ends_at)
+    }
+    pub fn duration(&self) -> TimeDelta {
+        TimeDelta::seconds(self.ends_at - self.starts_at)
+    }
+
+    pub fn local_start(&self) -> DateTime<Tz> {
+        self.start_in(KYIV)
+    }
+    pub fn local_end(&self) -> DateTime<Tz> {
+        self.end_in(KYIV)
+    }
+    /// Local date of the start.
+    pub fn date(&self) -> NaiveDate {
+        self.local_start().date_naive()
+    }
+    /// Local weekday of the start.
+    pub fn weekday(&self) -> Weekday {
+        self.local_start().weekday()
+    }
+
+    /// Start in the display time zone, e.g. for students abroad.
+    pub fn start_in(&self, tz: Tz) -> DateTime<Tz> {
+        self.start().with_timezone(&tz)
+    }
+    /// End in the display time zone, e.g. for students abroad.
+    pub fn end_in(&self, tz: Tz) -> DateTime<Tz> {
+        self.end().with_timezone(&tz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::Timelike;
+
+    #[test]
+    fn local_accessors_across_dst() {
+        // Saturday before and Monday after the spring transition, both at 07:45
+        for (timestamp, day, weekday) in [
+            (1743227100, 29, Weekday::Sat),
+            (1743396300, 31, Weekday::Mon),
+        ] {
+            let event = Event::test(1, timestamp);
+            assert_eq!(event.date(), NaiveDate::from_ymd_opt(2025, 3, day).unwrap());
+            assert_eq!(event.weekday(), weekday);
+            assert_eq!(
+                (event.local_start().hour(), event.local_start().minute()),
+                (7, 45)
+            );
+            assert_eq!(
+                (event.local_end().hour(), event.local_end().minute()),
+                (9, 20)
+            );
+        }
+    }
+
+    #[test]
+    fn display_time_zone() {
+        let event = Event::test(1, 1743396300);
+        assert_eq!(event.start_in(chrono_tz::Europe::Berlin).hour(), 6);
+        assert_eq!(event.start_in(chrono_tz::UTC), event.start());
+    }
+
+    #[test]
+    fn local_timestamp_dst() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        let spring = NaiveDate::from_ymd_opt(2025, 3, 30).unwrap();
+        assert_eq!(local_timestamp(spring, time(3, 30)), 1743298200); // 04:30 EEST
+        assert_eq!(local_timestamp(spring, time(4, 30)), 1743298200);
+
+        let autumn = NaiveDate::from_ymd_opt(2025, 10, 26).unwrap();
+        assert_eq!(local_timestamp(autumn, time(3, 30)), 1761438600); // 03:30 EEST
+
+        assert_eq!(
+            local_date(1743396300),
+            NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()
+        );
+    }
+}
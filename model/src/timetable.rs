@@ -0,0 +1,444 @@
+mod diff;
+mod merge;
+
+pub use diff::{EventChange, ModifiedEvent, TimetableDiff};
+
+use crate::{Event, Subject};
+
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    ops::{Bound, RangeBounds},
+};
+
+/// `(starts_at, id)` of the events, ordered by time.
+type TimeIndex = BTreeSet<(i64, i64)>;
+
+/// Whose timetable it is.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TimetableKind {
+    Group(i64),
+    Teacher(i64),
+    Auditorium(i64),
+}
+
+/// Events with their subjects, indexed by the start time
+/// and by the groups, teachers and auditoriums.
+#[derive(Clone, Debug, Default)]
+pub struct Timetable {
+    events: HashSet<Event>,
+    subjects: HashSet<Subject>,
+
+    by_time: TimeIndex,
+    by_group: HashMap<i64, TimeIndex>,
+    by_teacher: HashMap<i64, TimeIndex>,
+    by_auditorium: HashMap<i64, TimeIndex>,
+    /// The longest event duration, to look for events that are still going on.
+    longest: i64,
+}
+
+/// Equal if the events and subjects are field by field (unlike their own `Eq` by id),
+/// the indexes are derived from them.
+impl PartialEq for Timetable {
+    fn eq(&self, other: &Self) -> bool {
+        self.events.len() == other.events.len()
+            && self.subjects.len() == other.subjects.len()
+            && self
+                .events
+                .iter()
+                .all(|e| other.event(e.id).is_some_and(|o| same_event(e, o)))
+            && self.subjects.iter().all(|s| {
+                other
+                    .subject(s.id)
+                    .is_some_and(|o| (&s.abbr, &s.name) == (&o.abbr, &o.name))
+            })
+    }
+}
+
+fn same_event(a: &Event, b: &Event) -> bool {
+    let Event {
+        id,
+        starts_at,
+        ends_at,
+        kind,
+        count,
+        subject,
+        auditorium,
+        groups,
+        teachers,
+    } = a;
+    (
+        id, starts_at, ends_at, kind, count, subject, auditorium, groups, teachers,
+    ) == (
+        &b.id,
+        &b.starts_at,
+        &b.ends_at,
+        &b.kind,
+        &b.count,
+        &b.subject,
+        &b.auditorium,
+        &b.groups,
+        &b.teachers,
+    )
+}
+
+impl Eq for Timetable {}
+
+impl Timetable {
+    pub fn new(
+        events: impl IntoIterator<Item = Event>,
+        subjects: impl IntoIterator<Item = Subject>,
+    ) -> Self {
+        let mut timetable = Self {
+            subjects: HashSet::from_iter(subjects),
+            ..Default::default()
+        };
+        for event in events {
+            timetable.insert_event(event);
+        }
+        timetable
+    }
+
+    pub fn events(&self) -> &HashSet<Event> {
+        &self.events
+    }
+    pub fn subjects(&self) -> &HashSet<Subject> {
+        &self.subjects
+    }
+    pub fn into_parts(self) -> (HashSet<Event>, HashSet<Subject>) {
+        (self.events, self.subjects)
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn event(&self, id: i64) -> Option<&Event> {
+        self.events.get(&id)
+    }
+    pub fn subject(&self, id: i64) -> Option<&Subject> {
+        self.subjects.get(&id)
+    }
+
+    /// Insert or replace the event with the same id, returning the replaced one.
+    pub fn insert_event(&mut self, event: Event) -> Option<Event> {
+        let old = self.remove_event(event.id);
+
+        let key = (event.starts_at, event.id);
+        self.by_time.insert(key);
+        for group in &event.groups {
+            self.by_group.entry(*group).or_default().insert(key);
+        }
+        for teacher in &event.teachers {
+            self.by_teacher.entry(*teacher).or_default().insert(key);
+        }
+        self.by_auditorium
+            .entry(event.auditorium)
+            .or_default()
+            .insert(key);
+        self.longest = self.longest.max(event.ends_at - event.starts_at);
+
+        self.events.insert(event);
+        old
+    }
+
+    pub fn remove_event(&mut self, id: i64) -> Option<Event> {
+        let event = self.events.take(&id)?;
+
+        let key = (event.starts_at, event.id);
+        self.by_time.remove(&key);
+        for group in &event.groups {
+            unindex(&mut self.by_group, *group, &key);
+        }
+        for teacher in &event.teachers {
+            unindex(&mut self.by_teacher, *teacher, &key);
+        }
+        unindex(&mut self.by_auditorium, event.auditorium, &key);
+
+        if event.ends_at - event.starts_at >= self.longest {
+            self.longest = self
+                .events
+                .iter()
+                .map(|e| e.ends_at - e.starts_at)
+                .max()
+                .unwrap_or_default();
+        }
+
+        Some(event)
+    }
+
+    /// Insert or replace the subject with the same id.
+    pub fn insert_subject(&mut self, subject: Subject) -> Option<Subject> {
+        self.subjects.replace(subject)
+    }
+
+    pub fn remove_subject(&mut self, id: i64) -> Option<Subject> {
+        self.subjects.take(&id)
+    }
+
+    /// All the events, ordered by the start.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Event> {
+        self.resolve(self.by_time.iter())
+    }
+
+    /// Events that start within the `range` of timestamps, ordered by the start.
+    pub fn range(&self, range: impl RangeBounds<i64>) -> impl DoubleEndedIterator<Item = &Event> {
+        self.resolve(self.by_time.range(time_range(range)))
+    }
+
+    /// Events that take at least some time within `from..to`, ordered by the start.
+    pub fn overlapping(&self, from: i64, to: i64) -> impl DoubleEndedIterator<Item = &Event> {
+        self.range(from.saturating_sub(self.longest)..to.max(from))
+            .filter(move |e| e.ends_at > from)
+    }
+
+    /// Events that are going on at the `timestamp`.
+    pub fn current(&self, timestamp: i64) -> impl DoubleEndedIterator<Item = &Event> {
+        self.overlapping(timestamp, timestamp.saturating_add(1))
+    }
+
+    /// The first event that starts after the `timestamp`.
+    pub fn next(&self, timestamp: i64) -> Option<&Event> {
+        self.range((Bound::Excluded(timestamp), Bound::Unbounded))
+            .next()
+    }
+
+    /// Events of the group, teacher or auditorium, ordered by the start.
+    pub fn events_of(&self, kind: TimetableKind) -> impl DoubleEndedIterator<Item = &Event> {
+        let index = match kind {
+            TimetableKind::Group(id) => self.by_group.get(&id),
+            TimetableKind::Teacher(id) => self.by_teacher.get(&id),
+            TimetableKind::Auditorium(id) => self.by_auditorium.get(&id),
+        };
+        self.resolve(index.into_iter().flatten())
+    }
+
+    /// Events of the group, ordered by the start.
+    pub fn for_group(&self, id: i64) -> impl DoubleEndedIterator<Item = &Event> {
+        self.events_of(TimetableKind::Group(id))
+    }
+    /// Events of the teacher, ordered by the start.
+    pub fn for_teacher(&self, id: i64) -> impl DoubleEndedIterator<Item = &Event> {
+        self.events_of(TimetableKind::Teacher(id))
+    }
+    /// Events in the auditorium, ordered by the start.
+    pub fn for_auditorium(&self, id: i64) -> impl DoubleEndedIterator<Item = &Event> {
+        self.events_of(TimetableKind::Auditorium(id))
+    }
+
+    /// Ids of the groups that have at least one event.
+    pub fn groups(&self) -> impl Iterator<Item = i64> {
+        self.by_group.keys().copied()
+    }
+    /// Ids of the teachers that have at least one event.
+    pub fn teachers(&self) -> impl Iterator<Item = i64> {
+        self.by_teacher.keys().copied()
+    }
+    /// Ids of the auditoriums that have at least one event.
+    pub fn auditoriums(&self) -> impl Iterator<Item = i64> {
+        self.by_auditorium.keys().copied()
+    }
+
+    fn resolve<'a>(
+        &'a self,
+        keys: impl DoubleEndedIterator<Item = &'a (i64, i64)>,
+    ) -> impl DoubleEndedIterator<Item = &'a Event> {
+        keys.filter_map(|(_, id)| self.events.get(id))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod local {
+    use super::*;
+
+    use crate::time::{KYIV, local_timestamp};
+
+    use chrono::{Datelike, IsoWeek, NaiveDate, NaiveTime};
+    use std::collections::BTreeMap;
+
+    impl Timetable {
+        /// Events that start on the local `date`, ordered by the start.
+        pub fn on_date(&self, date: NaiveDate) -> impl DoubleEndedIterator<Item = &Event> {
+            let from = local_timestamp(date, NaiveTime::MIN);
+            let to = date
+                .succ_opt()
+                .map_or(i64::MAX, |next| local_timestamp(next, NaiveTime::MIN));
+            self.range(from..to)
+        }
+
+        /// Events grouped by the local date of the start.
+        pub fn by_date(&self) -> BTreeMap<NaiveDate, Vec<&Event>> {
+            let mut days = BTreeMap::<_, Vec<_>>::new();
+            for event in self.iter() {
+                days.entry(event.date()).or_default().push(event);
+            }
+            days
+        }
+
+        /// Events grouped by the ISO week of the local start.
+        pub fn by_week(&self) -> BTreeMap<IsoWeek, Vec<&Event>> {
+            let mut weeks = BTreeMap::<_, Vec<_>>::new();
+            for event in self.iter() {
+                weeks
+                    .entry(event.start_in(KYIV).iso_week())
+                    .or_default()
+                    .push(event);
+            }
+            weeks
+        }
+    }
+}
+
+fn unindex(index: &mut HashMap<i64, TimeIndex>, id: i64, key: &(i64, i64)) {
+    if let Some(keys) = index.get_mut(&id) {
+        keys.remove(key);
+        if keys.is_empty() {
+            index.remove(&id);
+        }
+    }
+}
+
+fn time_range(range: impl RangeBounds<i64>) -> impl RangeBounds<(i64, i64)> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => Bound::Included((s, i64::MIN)),
+        Bound::Excluded(&s) => Bound::Excluded((s, i64::MAX)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => Bound::Included((e, i64::MAX)),
+        Bound::Excluded(&e) => Bound::Excluded((e, i64::MIN)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60;
+    /// Monday 2025-09-15 07:45 EEST
+    const MONDAY: i64 = 1757911500;
+
+    fn timetable() -> Timetable {
+        Timetable::new(
+            [
+                Event::test(3, MONDAY + 24 * HOUR)
+                    .auditorium(2)
+                    .groups([1])
+                    .teachers([1]),
+                Event::test(1, MONDAY).groups([1, 2]).teachers([1]),
+                Event::test(2, MONDAY + 2 * HOUR)
+                    .auditorium(2)
+                    .groups([2])
+                    .teachers([1]),
+                Event::test(4, MONDAY + 7 * 24 * HOUR)
+                    .groups([1])
+                    .teachers([1]),
+            ],
+            [],
+        )
+    }
+
+    fn ids<'a>(events: impl IntoIterator<Item = &'a Event>) -> Vec<i64> {
+        events.into_iter().map(|e| e.id).collect()
+    }
+
+    #[test]
+    fn ordered() {
+        let timetable = timetable();
+        assert_eq!(ids(timetable.iter()), [1, 2, 3, 4]);
+        assert_eq!(ids(timetable.range(MONDAY..MONDAY + 2 * HOUR)), [1]);
+        assert_eq!(ids(timetable.range(MONDAY..=MONDAY + 2 * HOUR)), [1, 2]);
+        assert_eq!(ids(timetable.range(..MONDAY)), [0; 0]);
+    }
+
+    #[test]
+    fn current_and_next() {
+        let timetable = timetable();
+        assert_eq!(ids(timetable.current(MONDAY + HOUR)), [1]);
+        assert_eq!(ids(timetable.current(MONDAY + 95 * 60)), [0; 0]);
+        assert_eq!(timetable.next(MONDAY + HOUR).map(|e| e.id), Some(2));
+        assert_eq!(timetable.next(MONDAY + 30 * 24 * HOUR), None);
+        assert_eq!(
+            ids(timetable.overlapping(MONDAY + HOUR, MONDAY + 3 * HOUR)),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn secondary_indexes() {
+        let mut timetable = timetable();
+        assert_eq!(ids(timetable.for_group(1)), [1, 3, 4]);
+        assert_eq!(ids(timetable.for_group(2)), [1, 2]);
+        assert_eq!(ids(timetable.for_auditorium(2)), [2, 3]);
+        assert_eq!(ids(timetable.for_teacher(1)), [1, 2, 3, 4]);
+
+        // Moving the event to another auditorium and group
+        let old =
+            timetable.insert_event(Event::test(2, MONDAY + 3 * HOUR).groups([1]).teachers([1]));
+        assert_eq!(old.map(|e| e.starts_at), Some(MONDAY + 2 * HOUR));
+        assert_eq!(ids(timetable.for_group(2)), [1]);
+        assert_eq!(ids(timetable.for_auditorium(1)), [1, 2, 4]);
+
+        timetable.remove_event(1);
+        assert_eq!(timetable.groups().collect::<Vec<_>>(), [1]);
+        assert_eq!(timetable.len(), 3);
+    }
+
+    #[test]
+    fn remove_then_compare() {
+        let mut timetable = timetable();
+        let long = Event::test(5, MONDAY + 30 * HOUR)
+            .lasting(3 * HOUR)
+            .groups([1])
+            .teachers([1]);
+        timetable.insert_event(long);
+        assert_eq!(ids(timetable.current(MONDAY + 32 * HOUR)), [5]);
+
+        timetable.remove_event(5);
+        assert_eq!(timetable, self::timetable());
+        assert_eq!(timetable.longest, 95 * 60);
+        assert_eq!(ids(timetable.current(MONDAY + 32 * HOUR)), []);
+
+        // The same ids, but moved
+        let moved = Event::test(1, MONDAY + HOUR).groups([1, 2]).teachers([1]);
+        timetable.insert_event(moved);
+        assert_ne!(timetable, self::timetable());
+        let mut renamed = self::timetable();
+        renamed.insert_subject(Subject {
+            id: 1,
+            abbr: "ВМ".into(),
+            name: "Вища математика".into(),
+        });
+        let mut subject = renamed.clone();
+        subject.insert_subject(Subject {
+            id: 1,
+            abbr: "ДМ".into(),
+            name: "Дискретна математика".into(),
+        });
+        assert_ne!(renamed, subject);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn local_dates() {
+        use chrono::{Datelike, NaiveDate, Weekday};
+
+        let timetable = timetable();
+        let monday = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+
+        assert_eq!(ids(timetable.on_date(monday)), [1, 2]);
+        assert_eq!(ids(timetable.on_date(monday.succ_opt().unwrap())), [3]);
+
+        let weeks = timetable.by_week();
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(ids(weeks[&monday.iso_week()].iter().copied()), [1, 2, 3]);
+
+        let days = timetable.by_date();
+        assert_eq!(days.keys().next().map(|d| d.weekday()), Some(Weekday::Mon));
+        assert_eq!(days.len(), 3);
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{Event, Timetable};
+
+use std::collections::{BTreeSet, HashSet};
+
+/// How far (in seconds) an event with a new upstream id may have moved to still be matched.
+const MAX_SHIFT: i64 = 7 * 24 * 60 * 60;
+
+/// Difference between two snapshots of a timetable, everything is ordered by the start.
+#[derive(Clone, Debug, Default)]
+pub struct TimetableDiff {
+    pub added: Vec<Event>,
+    pub removed: Vec<Event>,
+    pub modified: Vec<ModifiedEvent>,
+}
+
+impl TimetableDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// The same class in both snapshots that has changed.
+#[derive(Clone, Debug)]
+pub struct ModifiedEvent {
+    pub old: Event,
+    pub new: Event,
+    pub changes: BTreeSet<EventChange>,
+}
+
+impl ModifiedEvent {
+    /// The upstream id is different, so the events were matched by the heuristic.
+    pub fn id_changed(&self) -> bool {
+        self.old.id != self.new.id
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum EventChange {
+    /// Start or end has changed.
+    Moved,
+    Auditorium,
+    /// Teachers were substituted.
+    Teachers,
+    Groups,
+    Kind,
+    Subject,
+    /// Pair number, when the time stays the same.
+    Count,
+}
+
+impl EventChange {
+    /// What has changed from `old` to `new`, empty if nothing.
+    pub fn between(old: &Event, new: &Event) -> BTreeSet<Self> {
+        [
+            (
+                old.starts_at != new.starts_at || old.ends_at != new.ends_at,
+                Self::Moved,
+            ),
+            (old.auditorium != new.auditorium, Self::Auditorium),
+            (old.teachers != new.teachers, Self::Teachers),
+            (old.groups != new.groups, Self::Groups),
+            (old.kind != new.kind, Self::Kind),
+            (old.subject != new.subject, Self::Subject),
+            (old.count != new.count, Self::Count),
+        ]
+        .into_iter()
+        .filter_map(|(changed, change)| changed.then_some(change))
+        .collect()
+    }
+}
+
+impl Timetable {
+    /// What has changed from `self` to the `newer` snapshot.
+    ///
+    /// Events are matched by id first. The rest are matched if they have the same subject, kind
+    /// and at least one common group, and either the same start or the same auditorium and
+    /// teachers within a week.
+    pub fn diff(&self, newer: &Self) -> TimetableDiff {
+        let mut diff = TimetableDiff::default();
+
+        let mut removed = Vec::new();
+        for old in self.iter() {
+            match newer.event(old.id) {
+                Some(new) => diff.modified.extend(modified(old, new)),
+                None => removed.push(old),
+            }
+        }
+        let added: Vec<_> = newer
+            .iter()
+            .filter(|e| self.event(e.id).is_none())
+            .collect();
+
+        let mut candidates = Vec::new();
+        for (r, old) in removed.iter().enumerate() {
+            for (a, new) in added.iter().enumerate() {
+                if let Some(score) = similarity(old, new) {
+                    candidates.push((score, r, a));
+                }
+            }
+        }
+        candidates.sort_unstable();
+
+        let mut matched_removed = HashSet::new();
+        let mut matched_added = HashSet::new();
+        for (_, r, a) in candidates {
+            if matched_removed.contains(&r) || matched_added.contains(&a) {
+                continue;
+            }
+            matched_removed.insert(r);
+            matched_added.insert(a);
+            diff.modified.push(ModifiedEvent {
+                old: removed[r].clone(),
+                new: added[a].clone(),
+                changes: EventChange::between(removed[r], added[a]),
+            });
+        }
+
+        diff.removed = removed
+            .into_iter()
+            .enumerate()
+            .filter(|(r, _)| !matched_removed.contains(r))
+            .map(|(_, e)| e.clone())
+            .collect();
+        diff.added = added
+            .into_iter()
+            .enumerate()
+            .filter(|(a, _)| !matched_added.contains(a))
+            .map(|(_, e)| e.clone())
+            .collect();
+        diff.modified.sort_by_key(|m| (m.new.starts_at, m.new.id));
+
+        diff
+    }
+}
+
+fn modified(old: &Event, new: &Event) -> Option<ModifiedEvent> {
+    let changes = EventChange::between(old, new);
+    (!changes.is_empty()).then(|| ModifiedEvent {
+        old: old.clone(),
+        new: new.clone(),
+        changes,
+    })
+}
+
+/// Lower is better, `None` if the events can't be the same class.
+fn similarity(old: &Event, new: &Event) -> Option<(bool, i64, usize)> {
+    if old.subject != new.subject
+        || old.kind != new.kind
+        || (old.groups.is_disjoint(&new.groups)
+            && !(old.groups.is_empty() && new.groups.is_empty()))
+    {
+        return None;
+    }
+
+    let same_time = old.starts_at == new.starts_at;
+    let shift = (old.starts_at - new.starts_at).abs();
+    let same_place = old.auditorium == new.auditorium && old.teachers == new.teachers;
+
+    if !(same_time || (same_place && shift <= MAX_SHIFT)) {
+        return None;
+    }
+
+    let differences = EventChange::between(old, new).len();
+    Some((!same_time, shift, differences))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::EventKind;
+
+    const HOUR: i64 = 60 * 60;
+    const MONDAY: i64 = 1757911500;
+
+    #[test]
+    fn same_ids() {
+        let old = Timetable::new(
+            [
+                Event::test(1, MONDAY).teachers([1]),
+                Event::test(2, MONDAY + 2 * HOUR).teachers([1]),
+            ],
+            [],
+        );
+
+        let moved = Event::test(1, MONDAY + HOUR).teachers([1]).auditorium(2);
+        let substituted = Event::test(2, MONDAY + 2 * HOUR).teachers([2]);
+        let new = Timetable::new(
+            [
+                moved,
+                substituted,
+                Event::test(3, MONDAY + 4 * HOUR).teachers([1]),
+            ],
+            [],
+        );
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.added.iter().map(|e| e.id).collect::<Vec<_>>(), [3]);
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.modified
+                .iter()
+                .map(|m| (m.new.id, m.changes.iter().copied().collect::<Vec<_>>()))
+                .collect::<Vec<_>>(),
+            [
+                (1, vec![EventChange::Moved, EventChange::Auditorium]),
+                (2, vec![EventChange::Teachers])
+            ]
+        );
+
+        assert!(new.diff(&new).is_empty());
+    }
+
+    #[test]
+    fn changed_ids() {
+        let other_subject = Event::test(2, MONDAY + 2 * HOUR).subject(2);
+        let old = Timetable::new([Event::test(1, MONDAY), other_subject], []);
+
+        let kind_changed = Event::test(20, MONDAY + 2 * HOUR).kind(EventKind::LaboratoryWork);
+        let new = Timetable::new([Event::test(10, MONDAY + 24 * HOUR), kind_changed], []);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.modified.len(), 1);
+        assert!(diff.modified[0].id_changed());
+        assert_eq!((diff.modified[0].old.id, diff.modified[0].new.id), (1, 10));
+        assert_eq!(
+            diff.modified[0].changes,
+            BTreeSet::from([EventChange::Moved])
+        );
+
+        // Different subject and kind are different classes
+        assert_eq!(diff.removed.iter().map(|e| e.id).collect::<Vec<_>>(), [2]);
+        assert_eq!(diff.added.iter().map(|e| e.id).collect::<Vec<_>>(), [20]);
+    }
+
+    #[test]
+    fn prefers_same_time() {
+        let old = Timetable::new([Event::test(1, MONDAY)], []);
+        let same_time = Event::test(11, MONDAY).auditorium(2);
+        let new = Timetable::new([Event::test(10, MONDAY + HOUR), same_time], []);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.modified[0].new.id, 11);
+        assert_eq!(diff.added.iter().map(|e| e.id).collect::<Vec<_>>(), [10]);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Event, Subject, Timetable};
+
+use std::collections::HashSet;
+
+impl Timetable {
+    /// Events of both timetables, see [`Timetable::merge`].
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.merge(other.clone());
+        union
+    }
+
+    /// Add the events of `other`. Copies of the same event are reconciled
+    /// by merging their groups and teachers, the rest is kept from `self`.
+    pub fn merge(&mut self, other: Self) {
+        let (events, subjects) = other.into_parts();
+
+        for subject in subjects {
+            if !self.subjects.contains(&subject) {
+                self.subjects.insert(subject);
+            }
+        }
+        for event in events {
+            let event = match self.event(event.id) {
+                Some(copy) => reconcile(copy, &event),
+                None => event,
+            };
+            self.insert_event(event);
+        }
+    }
+
+    /// Events present in both timetables, reconciled like in [`Timetable::merge`].
+    pub fn intersection(&self, other: &Self) -> Self {
+        let events = self
+            .iter()
+            .filter_map(|e| other.event(e.id).map(|copy| reconcile(e, copy)));
+        self.with_events(events, other)
+    }
+
+    /// Events of `self` that are absent in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let events = self.iter().filter(|e| other.event(e.id).is_none()).cloned();
+        self.with_events(events, other)
+    }
+
+    /// New timetable with only the subjects the `events` refer to.
+    fn with_events(&self, events: impl IntoIterator<Item = Event>, other: &Self) -> Self {
+        let events = Vec::from_iter(events);
+        let ids: HashSet<i64> = events.iter().map(|e| e.subject).collect();
+        let subjects = ids
+            .into_iter()
+            .filter_map(|id| self.subject(id).or_else(|| other.subject(id)))
+            .cloned()
+            .collect::<Vec<Subject>>();
+
+        Self::new(events, subjects)
+    }
+}
+
+fn reconcile(event: &Event, copy: &Event) -> Event {
+    let mut event = event.clone();
+    event.groups.extend(&copy.groups);
+    event.teachers.extend(&copy.teachers);
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn subject(id: i64) -> Subject {
+        Subject {
+            id,
+            abbr: format!("S{id}"),
+            name: format!("Subject {id}"),
+        }
+    }
+
+    fn ids<'a>(events: impl IntoIterator<Item = &'a Event>) -> Vec<i64> {
+        events.into_iter().map(|e| e.id).collect()
+    }
+
+    fn subject_ids(timetable: &Timetable) -> Vec<i64> {
+        let mut ids: Vec<_> = timetable.subjects().iter().map(|s| s.id).collect();
+        ids.sort();
+        ids
+    }
+
+    /// Group 1 timetable and an elective teacher 7 timetable sharing the lecture 2.
+    fn timetables() -> (Timetable, Timetable) {
+        (
+            Timetable::new(
+                [
+                    Event::test(1, 10_000).subject(1).groups([1]).teachers([5]),
+                    Event::test(2, 2 * 10_000)
+                        .subject(2)
+                        .groups([1])
+                        .teachers([7]),
+                ],
+                [subject(1), subject(2)],
+            ),
+            Timetable::new(
+                [
+                    Event::test(2, 2 * 10_000)
+                        .subject(2)
+                        .groups([1, 2])
+                        .teachers([7]),
+                    Event::test(3, 3 * 10_000)
+                        .subject(3)
+                        .groups([2])
+                        .teachers([7]),
+                ],
+                [subject(2), subject(3)],
+            ),
+        )
+    }
+
+    #[test]
+    fn union() {
+        let (group, teacher) = timetables();
+        let union = group.union(&teacher);
+
+        assert_eq!(ids(union.iter()), [1, 2, 3]);
+        assert_eq!(union.event(2).unwrap().groups, HashSet::from([1, 2]));
+        assert_eq!(ids(union.for_group(2)), [2, 3]);
+        assert_eq!(subject_ids(&union), [1, 2, 3]);
+    }
+
+    #[test]
+    fn intersection() {
+        let (group, teacher) = timetables();
+        let intersection = group.intersection(&teacher);
+
+        assert_eq!(ids(intersection.iter()), [2]);
+        assert_eq!(intersection.event(2).unwrap().groups, HashSet::from([1, 2]));
+        assert_eq!(subject_ids(&intersection), [2]);
+    }
+
+    #[test]
+    fn difference() {
+        let (group, teacher) = timetables();
+
+        let difference = group.difference(&teacher);
+        assert_eq!(ids(difference.iter()), [1]);
+        assert_eq!(subject_ids(&difference), [1]);
+
+        assert_eq!(ids(teacher.difference(&group).iter()), [3]);
+        assert!(group.difference(&group).is_empty());
+    }
+}